@@ -1,7 +1,31 @@
+use nalgebra::Vector2;
+
+#[cfg(test)]
+mod test;
+
 #[derive(Clone, Debug, Default, PartialEq)]
 pub enum Boundary {
     #[default]
     None,
     NoSlip,
     Slip,
+    Inlet {
+        velocity: Vector2<f64>,
+    },
+    Outlet {
+        pressure: f64,
+    },
+    FixedTemperature(f64),
+    Symmetry,
+}
+
+impl Boundary {
+    /// Checks if the boundary is a solid wall, which the fluid does not flow through.
+    /// A fixed temperature boundary is a wall as the temperature is imposed by a heated or cooled solid bounding the fluid.
+    pub fn is_wall(&self) -> bool {
+        matches!(
+            self,
+            Boundary::NoSlip | Boundary::Slip | Boundary::FixedTemperature(_)
+        )
+    }
 }
@@ -0,0 +1,15 @@
+use super::*;
+
+#[test]
+fn is_wall_test_1() {
+    assert!(!Boundary::None.is_wall());
+    assert!(Boundary::NoSlip.is_wall());
+    assert!(Boundary::Slip.is_wall());
+    assert!(!Boundary::Inlet {
+        velocity: Vector2::new(1.0, 0.0)
+    }
+    .is_wall());
+    assert!(!Boundary::Outlet { pressure: 0.0 }.is_wall());
+    assert!(Boundary::FixedTemperature(300.0).is_wall());
+    assert!(!Boundary::Symmetry.is_wall());
+}
@@ -41,6 +41,11 @@ pub enum MeshError {
         he_next: HalfEdgeIndex,
         he_next_prev: HalfEdgeIndex,
     },
+    #[error("The next half-edge does not start where the half-edge ends (origin : {he:?}, next : {he_next:?})")]
+    NextVertexNotCorrect {
+        he: HalfEdgeIndex,
+        he_next: HalfEdgeIndex,
+    },
     #[error("The object already exists")]
     AlreadyExists,
     #[error("The vertices is not contained in parent (vertex : {vertex:?}, parent : {parent:?})")]
@@ -48,4 +53,38 @@ pub enum MeshError {
         vertex: VertexIndex,
         parent: ParentIndex,
     },
+    #[error("The parent is not a cell (parent : {parent:?})")]
+    ParentIsNotACell { parent: ParentIndex },
+    #[error("The parent does not have the expected number of edges (parent : {parent:?}, expected : {expected:?}, got : {got:?})")]
+    WrongEdgeCount {
+        parent: ParentIndex,
+        expected: usize,
+        got: usize,
+    },
+    #[error("The chosen diagonal is outside of the quad (parent : {parent:?})")]
+    DiagonalOutsideQuad { parent: ParentIndex },
+    #[error("The maximum number of iterations has been reached ({max_iterations:?})")]
+    MaxIterationReached { max_iterations: usize },
+    #[error("The cell is not star-shaped from the point (parent : {parent:?})")]
+    NotStarShaped { parent: ParentIndex },
+    #[error("The new edge would cross an existing edge (half-edge : {he:?})")]
+    EdgeWouldCross { he: HalfEdgeIndex },
+    #[error("The vertex adjacency cache does not match the half-edges (vertex : {vertex:?})")]
+    VertexAdjacencyNotCorrect { vertex: VertexIndex },
+    #[error("The new edge would be outside of the parent (parent : {parent:?})")]
+    EdgeOutsideParent { parent: ParentIndex },
+    #[error("An edge or a vertex is shared by too many cells (vertex : {vertex:?})")]
+    NotManifold { vertex: VertexIndex },
+    #[error("The file could not be read (filename : {filename:?})")]
+    FileNotReadable { filename: String },
+    #[error("The file could not be parsed (line : {line:?})")]
+    ParsingError { line: usize },
+    #[error("The Euler characteristic of the mesh is not the expected one (got : {got:?}, expected : {expected:?})")]
+    UnexpectedEuler { got: i64, expected: i64 },
+    #[error("The vertex is still connected to some half-edges (vertex : {vertex:?})")]
+    VertexStillConnected { vertex: VertexIndex },
+    #[error("The cell has less than 3 vertices or the same vertex twice (parent : {parent:?})")]
+    DegenerateCell { parent: ParentIndex },
+    #[error("The edge would link a vertex to itself (vertex : {vertex:?})")]
+    SelfLoopEdge { vertex: VertexIndex },
 }
@@ -0,0 +1,261 @@
+//! Geometric functions working on raw points, independent from the mesh representation.
+
+use nalgebra::{Point2, Vector2};
+
+#[cfg(test)]
+mod test;
+
+/// Computes the centroid and the area of a polygon given by its vertices in cyclic order.
+/// Uses the shoelace formula so it is valid for non-convex polygons, whatever their orientation.
+///
+/// A degenerate polygon (zero area) gets the mean of its vertices as centroid.
+pub fn centroid_and_area(points: &[Point2<f64>]) -> (Point2<f64>, f64) {
+    let mut double_area = 0.0;
+    let mut weighted_sum = Point2::origin();
+
+    for i in 0..points.len() {
+        let a = points[i];
+        let b = points[(i + 1) % points.len()];
+        let cross = a.x * b.y - b.x * a.y;
+        double_area += cross;
+        weighted_sum += (a.coords + b.coords) * cross;
+    }
+
+    if double_area == 0.0 {
+        let mean = points
+            .iter()
+            .fold(Point2::origin(), |sum, point| sum + point.coords)
+            / points.len().max(1) as f64;
+        return (mean, 0.0);
+    }
+
+    (weighted_sum / (3.0 * double_area), double_area.abs() / 2.0)
+}
+
+/// Computes the signed area of a polygon given by its vertices in cyclic order with the shoelace formula.
+/// The area is positive for a counter-clockwise polygon and negative for a clockwise one.
+pub fn signed_polygon_area(points: &[Point2<f64>]) -> f64 {
+    (0..points.len())
+        .map(|i| {
+            let a = points[i];
+            let b = points[(i + 1) % points.len()];
+            a.x * b.y - b.x * a.y
+        })
+        .sum::<f64>()
+        / 2.0
+}
+
+/// Checks if a polygon given by its vertices in cyclic order is counter-clockwise.
+pub fn is_counter_clockwise(points: &[Point2<f64>]) -> bool {
+    signed_polygon_area(points) > 0.0
+}
+
+/// Computes the z component of the cross product of two vectors, same as ```Vector2::perp```.
+/// It is positive when ```b``` is counter-clockwise from ```a```.
+pub fn cross_2d(a: Vector2<f64>, b: Vector2<f64>) -> f64 {
+    a.perp(&b)
+}
+
+/// Computes the signed angle from ```a``` to ```b```, in (-pi, pi] and positive counter-clockwise.
+pub fn angle_between(a: Vector2<f64>, b: Vector2<f64>) -> f64 {
+    let angle = cross_2d(a, b).atan2(a.dot(&b));
+    // atan2 gives -pi for anti-parallel vectors with a negative zero cross product
+    if angle <= -std::f64::consts::PI {
+        std::f64::consts::PI
+    } else {
+        angle
+    }
+}
+
+/// Checks if two segments intersect, touching counts as an intersection.
+pub fn segments_intersect(
+    segment_1: (Point2<f64>, Point2<f64>),
+    segment_2: (Point2<f64>, Point2<f64>),
+) -> bool {
+    let orientation = |a: Point2<f64>, b: Point2<f64>, c: Point2<f64>| (b - a).perp(&(c - a));
+    // Only called on collinear points
+    let in_box = |a: Point2<f64>, b: Point2<f64>, c: Point2<f64>| {
+        (c.x >= a.x.min(b.x))
+            && (c.x <= a.x.max(b.x))
+            && (c.y >= a.y.min(b.y))
+            && (c.y <= a.y.max(b.y))
+    };
+
+    let (p1, p2) = segment_1;
+    let (q1, q2) = segment_2;
+    let d1 = orientation(q1, q2, p1);
+    let d2 = orientation(q1, q2, p2);
+    let d3 = orientation(p1, p2, q1);
+    let d4 = orientation(p1, p2, q2);
+
+    if (d1 * d2 < 0.0) && (d3 * d4 < 0.0) {
+        return true;
+    }
+
+    ((d1 == 0.0) && in_box(q1, q2, p1))
+        || ((d2 == 0.0) && in_box(q1, q2, p2))
+        || ((d3 == 0.0) && in_box(p1, p2, q1))
+        || ((d4 == 0.0) && in_box(p1, p2, q2))
+}
+
+/// Computes the area of the intersection of two convex polygons, given by their vertices in cyclic order.
+/// Uses Sutherland-Hodgman clipping of ```a``` by the edges of ```b```.
+pub fn polygon_intersection_area(a: &[Point2<f64>], b: &[Point2<f64>]) -> f64 {
+    // Clipping needs a counter-clockwise clip polygon
+    let mut clip = b.to_vec();
+    if signed_polygon_area(b) < 0.0 {
+        clip.reverse();
+    }
+
+    let mut result = a.to_vec();
+    for i in 0..clip.len() {
+        if result.is_empty() {
+            return 0.0;
+        }
+        let edge_start = clip[i];
+        let edge = clip[(i + 1) % clip.len()] - edge_start;
+        let side = |p: Point2<f64>| edge.perp(&(p - edge_start));
+
+        let input = std::mem::take(&mut result);
+        for j in 0..input.len() {
+            let current = input[j];
+            let next = input[(j + 1) % input.len()];
+            let (side_current, side_next) = (side(current), side(next));
+
+            if side_current >= 0.0 {
+                result.push(current);
+            }
+            if (side_current >= 0.0) != (side_next >= 0.0) {
+                let t = side_current / (side_current - side_next);
+                result.push(current + (next - current) * t);
+            }
+        }
+    }
+
+    centroid_and_area(&result).1
+}
+
+/// Checks if ```d``` is strictly inside the circle going through ```a```, ```b``` and ```c```.
+/// The points are considered cocircular when the in-circle determinant is within ```epsilon``` of zero, in which case false is returned.
+/// The determinant scales as a length to the fourth power, so should ```epsilon```.
+pub fn in_circle(
+    a: Point2<f64>,
+    b: Point2<f64>,
+    c: Point2<f64>,
+    d: Point2<f64>,
+    epsilon: f64,
+) -> bool {
+    let (a, b, c) = (a - d, b - d, c - d);
+    let determinant = a.norm_squared() * b.perp(&c) - b.norm_squared() * a.perp(&c)
+        + c.norm_squared() * a.perp(&b);
+
+    // The sign of the determinant depends on the orientation of the triangle
+    if a.perp(&b) + b.perp(&c) + c.perp(&a) >= 0.0 {
+        determinant > epsilon
+    } else {
+        -determinant > epsilon
+    }
+}
+
+/// Computes the center of the circle going through ```a```, ```b``` and ```c```.
+/// Returns None when the points are collinear, that is when twice the signed area of the triangle is within ```epsilon``` of zero.
+pub fn circumcenter(
+    a: Point2<f64>,
+    b: Point2<f64>,
+    c: Point2<f64>,
+    epsilon: f64,
+) -> Option<Point2<f64>> {
+    let (b, c) = (b - a, c - a);
+    let double_area = b.perp(&c);
+    if double_area.abs() <= epsilon {
+        return None;
+    }
+
+    let center = Vector2::new(
+        c.y * b.norm_squared() - b.y * c.norm_squared(),
+        b.x * c.norm_squared() - c.x * b.norm_squared(),
+    ) / (2.0 * double_area);
+    Some(a + center)
+}
+
+/// Computes the radius of the circle going through ```a```, ```b``` and ```c```, see ```circumcenter```.
+pub fn circumradius(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, epsilon: f64) -> Option<f64> {
+    circumcenter(a, b, c, epsilon).map(|center| (a - center).norm())
+}
+
+/// Checks that a polygon, given by its vertices in cyclic order, is simple: no two non-adjacent edges intersect.
+/// Useful to validate a boundary before building a mesh from it.
+pub fn is_simple_polygon(points: &[Point2<f64>]) -> bool {
+    let len = points.len();
+    let edge = |i: usize| (points[i], points[(i + 1) % len]);
+
+    for i in 0..len {
+        // Edges i - 1 and i + 1 share a vertex with edge i
+        for j in (i + 2)..len {
+            if (i == 0) && (j == len - 1) {
+                continue;
+            }
+            if segments_intersect(edge(i), edge(j)) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Computes the winding number of a polygon, given by its vertices in cyclic order, around a point.
+/// It is 1 for a point inside a counter-clockwise polygon, -1 inside a clockwise one and 0 outside.
+pub fn winding_number(p: Point2<f64>, polygon: &[Point2<f64>]) -> i32 {
+    let mut winding = 0;
+
+    for i in 0..polygon.len() {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % polygon.len()];
+        let side = (b - a).perp(&(p - a));
+        if a.y <= p.y {
+            if (b.y > p.y) && (side > 0.0) {
+                winding += 1;
+            }
+        } else if (b.y <= p.y) && (side < 0.0) {
+            winding -= 1;
+        }
+    }
+
+    winding
+}
+
+/// Smooths a polyline with Chaikin's corner cutting, each iteration replacing each edge by the points at its quarter and three quarters.
+/// A closed polyline doubles its number of points at each iteration.
+/// An open polyline keeps its endpoints fixed, going from ```n``` to ```2 n``` points at each iteration.
+pub fn chaikin_smooth(points: &[Point2<f64>], iterations: usize, closed: bool) -> Vec<Point2<f64>> {
+    let mut result = points.to_vec();
+    if points.len() < 2 {
+        return result;
+    }
+
+    for _ in 0..iterations {
+        let edges_len = if closed {
+            result.len()
+        } else {
+            result.len() - 1
+        };
+
+        let mut smoothed = Vec::with_capacity(2 * result.len());
+        if !closed {
+            smoothed.push(result[0]);
+        }
+        for i in 0..edges_len {
+            let a = result[i];
+            let b = result[(i + 1) % result.len()];
+            smoothed.push(a.lerp(&b, 0.25));
+            smoothed.push(a.lerp(&b, 0.75));
+        }
+        if !closed {
+            smoothed.push(result[result.len() - 1]);
+        }
+        result = smoothed;
+    }
+
+    result
+}
@@ -0,0 +1,262 @@
+use super::*;
+
+#[test]
+fn centroid_and_area_test_1() {
+    let square = [
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(0.0, 2.0),
+    ];
+
+    let (centroid, area) = centroid_and_area(&square);
+    assert!((centroid - Point2::new(1.0, 1.0)).norm() < 1e-12);
+    assert!((area - 4.0).abs() < 1e-12);
+
+    let reversed: Vec<Point2<f64>> = square.into_iter().rev().collect();
+    let (centroid, area) = centroid_and_area(&reversed);
+    assert!((centroid - Point2::new(1.0, 1.0)).norm() < 1e-12);
+    assert!((area - 4.0).abs() < 1e-12);
+}
+
+#[test]
+fn centroid_and_area_test_2() {
+    let triangle = [
+        Point2::new(0.0, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(0.0, 3.0),
+    ];
+
+    let (centroid, area) = centroid_and_area(&triangle);
+    assert!((centroid - Point2::new(1.0, 1.0)).norm() < 1e-12);
+    assert!((area - 4.5).abs() < 1e-12);
+}
+
+#[test]
+fn centroid_and_area_test_3() {
+    let unit_square = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+
+    let (centroid, area) = centroid_and_area(&unit_square);
+    assert!((centroid - Point2::new(0.5, 0.5)).norm() < 1e-12);
+    assert!((area - 1.0).abs() < 1e-12);
+
+    // Unit square and a triangle of area 1.5 centered at (2, 1/3), the mean of the vertices is (1.25, 0.5)
+    let trapezoid = [
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+
+    let (centroid, area) = centroid_and_area(&trapezoid);
+    assert!((centroid - Point2::new(1.4, 0.4)).norm() < 1e-12);
+    assert!((area - 2.5).abs() < 1e-12);
+}
+
+#[test]
+fn segments_intersect_test_1() {
+    let a = (Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+
+    assert!(segments_intersect(
+        a,
+        (Point2::new(0.0, 1.0), Point2::new(1.0, 0.0))
+    ));
+    // Touching
+    assert!(segments_intersect(
+        a,
+        (Point2::new(0.5, 0.5), Point2::new(1.0, 0.0))
+    ));
+    // Collinear and overlapping
+    assert!(segments_intersect(
+        a,
+        (Point2::new(0.5, 0.5), Point2::new(2.0, 2.0))
+    ));
+    // Collinear and disjoint
+    assert!(!segments_intersect(
+        a,
+        (Point2::new(1.5, 1.5), Point2::new(2.0, 2.0))
+    ));
+    assert!(!segments_intersect(
+        a,
+        (Point2::new(1.0, 0.0), Point2::new(2.0, 0.0))
+    ));
+}
+
+#[test]
+fn polygon_intersection_area_test_1() {
+    let square = |x: f64, y: f64| {
+        [
+            Point2::new(x, y),
+            Point2::new(x + 1.0, y),
+            Point2::new(x + 1.0, y + 1.0),
+            Point2::new(x, y + 1.0),
+        ]
+    };
+
+    assert!((polygon_intersection_area(&square(0.0, 0.0), &square(0.0, 0.0)) - 1.0).abs() < 1e-12);
+    assert!((polygon_intersection_area(&square(0.0, 0.0), &square(0.5, 0.5)) - 0.25).abs() < 1e-12);
+    assert_eq!(
+        polygon_intersection_area(&square(0.0, 0.0), &square(2.0, 0.0)),
+        0.0
+    );
+
+    // Clockwise clip polygon
+    let mut clockwise = square(0.5, 0.0);
+    clockwise.reverse();
+    assert!((polygon_intersection_area(&square(0.0, 0.0), &clockwise) - 0.5).abs() < 1e-12);
+
+    let triangle = [
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(0.0, 2.0),
+    ];
+    assert!((polygon_intersection_area(&triangle, &square(0.0, 0.0)) - 1.0).abs() < 1e-12);
+    assert!((polygon_intersection_area(&triangle, &square(0.5, 0.5)) - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn in_circle_test_1() {
+    let a = Point2::new(0.0, 0.0);
+    let b = Point2::new(1.0, 0.0);
+    let c = Point2::new(0.0, 1.0);
+
+    assert!(in_circle(a, b, c, Point2::new(0.5, 0.5), 1e-12));
+    assert!(in_circle(c, b, a, Point2::new(0.5, 0.5), 1e-12));
+    assert!(!in_circle(a, b, c, Point2::new(2.0, 2.0), 1e-12));
+    // Cocircular
+    assert!(!in_circle(a, b, c, Point2::new(1.0, 1.0), 1e-12));
+    assert!(!in_circle(a, b, c, Point2::new(1.0, 1.0 - 1e-9), 1e-6));
+    assert!(in_circle(a, b, c, Point2::new(1.0, 1.0 - 1e-9), 0.0));
+}
+
+#[test]
+fn circumcenter_test_1() {
+    let (a, b, c) = (
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+    );
+
+    let center = circumcenter(a, b, c, 1e-12).unwrap();
+    assert!((center - Point2::new(0.5, 0.5)).norm() < 1e-12);
+    // Same circle whatever the orientation
+    let center = circumcenter(a, c, b, 1e-12).unwrap();
+    assert!((center - Point2::new(0.5, 0.5)).norm() < 1e-12);
+    assert!((circumradius(a, b, c, 1e-12).unwrap() - 0.5_f64.sqrt()).abs() < 1e-12);
+
+    // Collinear
+    assert_eq!(circumcenter(a, b, Point2::new(2.0, 0.0), 1e-12), None);
+    assert_eq!(circumradius(a, b, Point2::new(2.0, 1e-14), 1e-12), None);
+}
+
+#[test]
+fn is_simple_polygon_test_1() {
+    let convex = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.5, 1.0),
+        Point2::new(0.5, 1.5),
+        Point2::new(-0.5, 1.0),
+    ];
+    assert!(is_simple_polygon(&convex));
+
+    let figure_eight = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+    ];
+    assert!(!is_simple_polygon(&figure_eight));
+}
+
+#[test]
+fn winding_number_test_1() {
+    let square = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+
+    assert_eq!(winding_number(Point2::new(0.5, 0.5), &square), 1);
+    assert_eq!(winding_number(Point2::new(1.5, 0.5), &square), 0);
+    assert_eq!(winding_number(Point2::new(0.5, -0.5), &square), 0);
+
+    let reversed: Vec<Point2<f64>> = square.into_iter().rev().collect();
+    assert_eq!(winding_number(Point2::new(0.5, 0.5), &reversed), -1);
+}
+
+#[test]
+fn signed_polygon_area_test_1() {
+    // L-shape made of three unit squares
+    let l_shape = [
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(0.0, 2.0),
+    ];
+
+    assert!((signed_polygon_area(&l_shape) - 3.0).abs() < 1e-12);
+    assert!(is_counter_clockwise(&l_shape));
+
+    let reversed: Vec<Point2<f64>> = l_shape.into_iter().rev().collect();
+    assert!((signed_polygon_area(&reversed) + 3.0).abs() < 1e-12);
+    assert!(!is_counter_clockwise(&reversed));
+
+    // Mean of the centroids of the three squares, outside of the convex part
+    let (centroid, area) = centroid_and_area(&l_shape);
+    assert!((centroid - Point2::new(5.0 / 6.0, 5.0 / 6.0)).norm() < 1e-12);
+    assert!((area - 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn chaikin_smooth_test_1() {
+    let square = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+
+    let smoothed = chaikin_smooth(&square, 3, true);
+    assert_eq!(smoothed.len(), 4 * 8);
+    // Corners are cut while the symmetry is kept
+    let (centroid, area) = centroid_and_area(&smoothed);
+    assert!((centroid - Point2::new(0.5, 0.5)).norm() < 1e-12);
+    assert!(area < 1.0);
+    assert!(area > std::f64::consts::PI / 4.0);
+    assert!(is_simple_polygon(&smoothed));
+
+    let smoothed = chaikin_smooth(&square[..3], 2, false);
+    assert_eq!(smoothed.len(), 12);
+    assert_eq!(smoothed[0], square[0]);
+    assert_eq!(smoothed[11], square[2]);
+}
+
+#[test]
+fn angle_between_test_1() {
+    let x = Vector2::new(1.0, 0.0);
+    let y = Vector2::new(0.0, 2.0);
+
+    assert_eq!(cross_2d(x, y), 2.0);
+    assert_eq!(cross_2d(y, x), -2.0);
+
+    // Orthogonal
+    assert!((angle_between(x, y) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert!((angle_between(y, x) + std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+
+    // Anti-parallel, whatever the sign of the zero cross product
+    assert_eq!(angle_between(x, -x), std::f64::consts::PI);
+    assert_eq!(
+        angle_between(x, Vector2::new(-1.0, -0.0)),
+        std::f64::consts::PI
+    );
+    assert_eq!(angle_between(x, 3.0 * x), 0.0);
+}
@@ -6,4 +6,5 @@
 
 pub mod boundary;
 pub mod errors;
+pub mod geometry;
 pub mod mesh;
@@ -1,9 +1,12 @@
-use crate::{boundary::Boundary, errors::MeshError};
+use crate::{boundary::Boundary, errors::MeshError, geometry};
 use indices::*;
-use nalgebra::Point2;
+use nalgebra::{Point2, Vector2};
 
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, Write};
+use std::iter::FusedIterator;
+use std::str::FromStr;
 
 pub mod indices;
 
@@ -20,10 +23,20 @@ pub enum Parent {
     Boundary(Boundary),
 }
 
+/// Orientation of a polygon.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum Winding {
+    /// Counter-clockwise
+    #[default]
+    Ccw,
+    /// Clockwise
+    Cw,
+}
+
 /// Array based Half-edge data-structure mesh representation
 /// Supports meshes of up to a billion element.
 /// Since the crate is built for cfd on a classic computer (not HPC) it is easily enough.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct Base2DMesh {
     //```he``` is for Half-edge
     he_to_vertex: Vec<VertexIndex>,
@@ -36,6 +49,9 @@ pub struct Base2DMesh {
     parents: Vec<Parent>,
 
     parent_to_first_he: Vec<HalfEdgeIndex>,
+
+    /// Optional cache of the half-edges starting from each vertex, see ```rebuild_vertex_adjacency```
+    vertex_to_he: Option<Vec<Vec<HalfEdgeIndex>>>,
 }
 
 impl Base2DMesh {
@@ -62,25 +78,53 @@ impl Base2DMesh {
         self.parents.len()
     }
 
+    /// Returns the number of parents which are cells.
+    pub fn cells_len(&self) -> usize {
+        self.parents
+            .iter()
+            .filter(|parent| **parent == Parent::Cell)
+            .count()
+    }
+
+    /// Returns the approximate memory used by the mesh, in bytes.
+    /// Counts the allocated capacity of the arrays, not only their length.
+    pub fn memory_usage(&self) -> usize {
+        size_of::<Self>()
+            + self.he_to_vertex.capacity() * size_of::<VertexIndex>()
+            + self.he_to_twin.capacity() * size_of::<HalfEdgeIndex>()
+            + self.he_to_next_he.capacity() * size_of::<HalfEdgeIndex>()
+            + self.he_to_prev_he.capacity() * size_of::<HalfEdgeIndex>()
+            + self.he_to_parent.capacity() * size_of::<ParentIndex>()
+            + self.vertices.capacity() * size_of::<Point2<f64>>()
+            + self.parents.capacity() * size_of::<Parent>()
+            + self.parent_to_first_he.capacity() * size_of::<HalfEdgeIndex>()
+            + self.vertex_to_he.as_ref().map_or(0, |vertex_to_he| {
+                vertex_to_he.capacity() * size_of::<Vec<HalfEdgeIndex>>()
+                    + vertex_to_he
+                        .iter()
+                        .map(|he| he.capacity() * size_of::<HalfEdgeIndex>())
+                        .sum::<usize>()
+            })
+    }
+
     /// Gets all half-edges from a parent (Cell or boundary).
     pub fn he_from_parent(&self, parent_id: ParentIndex) -> Vec<HalfEdgeIndex> {
-        let first_he = self.parent_to_first_he[parent_id];
-        let mut result = vec![first_he];
-        let mut current_he = self.he_to_next_he[first_he];
+        self.he_iter_from_parent(parent_id).collect()
+    }
 
-        while first_he != current_he {
-            //println!("{:?}, {:?}", current_he, first_he);
-            result.push(current_he);
-            current_he = self.he_to_next_he[current_he];
+    /// Iterates over the half-edges of a parent (Cell or boundary) without allocating.
+    pub fn he_iter_from_parent(&self, parent_id: ParentIndex) -> ParentHalfEdges<'_> {
+        let first_he = self.parent_to_first_he[parent_id];
+        ParentHalfEdges {
+            mesh: self,
+            first_he,
+            current_he: Some(first_he),
         }
-
-        result
     }
 
     /// Gets all vertices from a parent (Cell or boundary).
     pub fn vertices_from_parent(&self, parent_id: ParentIndex) -> Vec<VertexIndex> {
-        self.he_from_parent(parent_id)
-            .into_iter()
+        self.he_iter_from_parent(parent_id)
             .map(|he_id| self.he_to_vertex[he_id])
             .collect()
     }
@@ -105,11 +149,76 @@ impl Base2DMesh {
         self.he_to_prev_he[he_id]
     }
 
+    /// Gets the unit normal of an HalfEdge, pointing to its right side.
+    /// Since cells are counter-clockwise loops, it points out of the parent of a cell half-edge.
+    pub fn normal_from_he(&self, he_id: HalfEdgeIndex) -> Vector2<f64> {
+        let [start, end] = self.vertices_from_he(he_id);
+        let edge = self.vertices[end] - self.vertices[start];
+        Vector2::new(edge.y, -edge.x).normalize()
+    }
+
+    /// Gets the distinct outward normals of the boundary edges connected to a vertex.
+    /// At a sharp corner two normals are returned, callers can then decide how to combine them.
+    ///
+    /// Returns an empty vector for interior vertices.
+    pub fn corner_normals(&self, vertex_id: VertexIndex) -> Vec<Vector2<f64>> {
+        let mut result: Vec<Vector2<f64>> = Vec::new();
+
+        for he_out in self.he_from_vertex(vertex_id) {
+            if !matches!(self.parents[self.he_to_parent[he_out]], Parent::Boundary(_)) {
+                continue;
+            }
+            // Boundary half-edges have the domain on their right
+            for he in [self.he_to_prev_he[he_out], he_out] {
+                let normal = -self.normal_from_he(he);
+                if result.iter().all(|other| (other - normal).norm() > 1e-10) {
+                    result.push(normal);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Gets the area of a parent.
+    pub fn area_from_parent(&self, parent_id: ParentIndex) -> f64 {
+        self.centroid_and_area_from_parent(parent_id).1
+    }
+
+    /// Gets the centroid of a parent.
+    pub fn centroid_from_parent(&self, parent_id: ParentIndex) -> Point2<f64> {
+        self.centroid_and_area_from_parent(parent_id).0
+    }
+
+    fn centroid_and_area_from_parent(&self, parent_id: ParentIndex) -> (Point2<f64>, f64) {
+        let points: Vec<Point2<f64>> = self
+            .vertices_from_parent(parent_id)
+            .into_iter()
+            .map(|vertex| self.vertices[vertex])
+            .collect();
+        geometry::centroid_and_area(&points)
+    }
+
+    /// Gets the smallest interior angle of a parent (in radians).
+    /// The parent is expected counter-clockwise, as cells are, so that reflex angles are above pi.
+    pub fn min_angle_from_parent(&self, parent_id: ParentIndex) -> f64 {
+        let vertices = self.vertices_from_parent(parent_id);
+        let len = vertices.len();
+
+        (0..len)
+            .map(|i| {
+                let vertex = self.vertices[vertices[i]];
+                let to_prev = self.vertices[vertices[(i + len - 1) % len]] - vertex;
+                let to_next = self.vertices[vertices[(i + 1) % len]] - vertex;
+                geometry::angle_between(to_next, to_prev).rem_euclid(2.0 * std::f64::consts::PI)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
     /// Gets the parents adjacent to another.
     /// This may have strange behaviours when used on a boundary.
     pub fn neighbors_from_parent(&self, parent_id: ParentIndex) -> Vec<ParentIndex> {
-        self.he_from_parent(parent_id)
-            .into_iter()
+        self.he_iter_from_parent(parent_id)
             .map(|he_id| self.he_to_parent[self.he_to_twin[he_id]])
             .collect()
     }
@@ -120,7 +229,12 @@ impl Base2DMesh {
     }
 
     /// Gets the half-edges connected to a vertex
+    /// Uses the vertex adjacency cache when it is built, otherwise all the half-edges are scanned.
     pub fn he_from_vertex(&self, vertex_id: VertexIndex) -> Vec<HalfEdgeIndex> {
+        if let Some(he_slice) = self.he_slice_from_vertex(vertex_id) {
+            return he_slice.to_vec();
+        }
+
         let mut result = Vec::new();
         for i in 0..self.he_len() {
             if self.he_to_vertex[HalfEdgeIndex(i)] == vertex_id {
@@ -130,6 +244,237 @@ impl Base2DMesh {
         result
     }
 
+    /// Gets the number of edges connected to a vertex.
+    pub fn vertex_valence(&self, vertex_id: VertexIndex) -> usize {
+        // Each edge has exactly one half-edge starting from the vertex
+        self.he_from_vertex(vertex_id).len()
+    }
+
+    /// Checks if a vertex is on a boundary.
+    /// As boundary parents are closed loops, a boundary half-edge always starts from a boundary vertex.
+    pub fn is_boundary_vertex(&self, vertex_id: VertexIndex) -> bool {
+        self.he_from_vertex(vertex_id)
+            .into_iter()
+            .any(|he| matches!(self.parents[self.he_to_parent[he]], Parent::Boundary(_)))
+    }
+
+    /// Gets all the boundary vertices, loop after loop, each loop in the order of its half-edges.
+    pub fn boundary_vertices(&self) -> Vec<VertexIndex> {
+        self.boundary_loops()
+            .into_iter()
+            .flatten()
+            .map(|he| self.he_to_vertex[he])
+            .collect()
+    }
+
+    /// Gets all the half-edges whose parent is a boundary.
+    pub fn boundary_half_edges(&self) -> Vec<HalfEdgeIndex> {
+        (0..self.he_len())
+            .map(HalfEdgeIndex)
+            .filter(|he| matches!(self.parents[self.he_to_parent[*he]], Parent::Boundary(_)))
+            .collect()
+    }
+
+    /// Gets the distinct loops formed by the boundary half-edges, each loop in the order of the next half-edges.
+    /// A simply connected domain has one boundary loop, each hole adds another one.
+    pub fn boundary_loops(&self) -> Vec<Vec<HalfEdgeIndex>> {
+        let mut visited = vec![false; self.he_len()];
+        let mut loops = Vec::new();
+
+        for he in self.boundary_half_edges() {
+            if visited[he.0] {
+                continue;
+            }
+            let mut boundary_loop = Vec::new();
+            let mut current_he = he;
+            while !visited[current_he.0] {
+                visited[current_he.0] = true;
+                boundary_loop.push(current_he);
+                current_he = self.he_to_next_he[current_he];
+            }
+            loops.push(boundary_loop);
+        }
+
+        loops
+    }
+
+    /// Counts the distinct loops formed by the boundary half-edges, see ```boundary_loops```.
+    pub fn num_boundary_loops(&self) -> usize {
+        self.boundary_loops().len()
+    }
+
+    /// Computes the discrete (umbrella) Laplacian of a field defined on the vertices.
+    /// For each vertex it is the average of the values on its one-ring minus its own value.
+    /// Boundary vertices only use the neighbors linked to them by a boundary edge.
+    ///
+    /// # Panics
+    ///
+    /// Panics if ```field``` does not have exactly one value per vertex.
+    pub fn vertex_laplacian(&self, field: &[f64]) -> Vec<f64> {
+        assert_eq!(
+            field.len(),
+            self.vertices_len(),
+            "The field must have one value per vertex"
+        );
+
+        let is_boundary_he =
+            |he: HalfEdgeIndex| matches!(self.parents[self.he_to_parent[he]], Parent::Boundary(_));
+
+        let mut is_boundary_vertex = vec![false; self.vertices_len()];
+        for i in 0..self.he_len() {
+            if is_boundary_he(HalfEdgeIndex(i)) {
+                is_boundary_vertex[self.he_to_vertex[HalfEdgeIndex(i)].0] = true;
+            }
+        }
+
+        // Each edge is seen once from each of its vertices through its two half-edges
+        let mut sum = vec![0.0; self.vertices_len()];
+        let mut count = vec![0usize; self.vertices_len()];
+        for i in 0..self.he_len() {
+            let he = HalfEdgeIndex(i);
+            let [origin, end] = self.vertices_from_he(he);
+            if !is_boundary_vertex[origin.0]
+                || is_boundary_he(he)
+                || is_boundary_he(self.he_to_twin[he])
+            {
+                sum[origin.0] += field[end.0];
+                count[origin.0] += 1;
+            }
+        }
+
+        (0..self.vertices_len())
+            .map(|i| {
+                if count[i] == 0 {
+                    0.0
+                } else {
+                    sum[i] / count[i] as f64 - field[i]
+                }
+            })
+            .collect()
+    }
+
+    /// Gets the discrete signed curvature of the boundary at a vertex.
+    /// It is the turning angle between the two boundary edges around the vertex divided by their average length.
+    /// The curvature is positive where the domain is convex.
+    ///
+    /// Returns ```None``` if the vertex is not on a boundary.
+    pub fn boundary_curvature(&self, vertex_id: VertexIndex) -> Option<f64> {
+        let he_out = self
+            .he_from_vertex(vertex_id)
+            .into_iter()
+            .find(|he| matches!(self.parents[self.he_to_parent[*he]], Parent::Boundary(_)))?;
+        let he_in = self.he_to_prev_he[he_out];
+
+        let [start, middle] = self.vertices_from_he(he_in);
+        let end = self.he_to_vertex[self.he_to_twin[he_out]];
+        let edge_in = self.vertices[middle] - self.vertices[start];
+        let edge_out = self.vertices[end] - self.vertices[middle];
+
+        // Boundary half-edges go clockwise around the domain, hence the minus sign
+        let turning_angle = -geometry::angle_between(edge_in, edge_out);
+
+        Some(turning_angle * 2.0 / (edge_in.norm() + edge_out.norm()))
+    }
+
+    /// Gets the half-edges connected to a vertex without allocating, only available when the vertex adjacency cache is built.
+    pub fn he_slice_from_vertex(&self, vertex_id: VertexIndex) -> Option<&[HalfEdgeIndex]> {
+        self.vertex_to_he
+            .as_ref()
+            .map(|vertex_to_he| vertex_to_he[vertex_id].as_slice())
+    }
+
+    /// Builds the cache of the half-edges starting from each vertex, making ```he_from_vertex``` cheap.
+    /// Once built, the cache is kept up to date by the modifications of ```Modifiable2DMesh```.
+    pub fn rebuild_vertex_adjacency(&mut self) {
+        let mut vertex_to_he = vec![Vec::new(); self.vertices_len()];
+        for (i, vertex) in self.he_to_vertex.iter().enumerate() {
+            vertex_to_he[*vertex].push(HalfEdgeIndex(i));
+        }
+        self.vertex_to_he = Some(vertex_to_he);
+    }
+
+    /// Registers a new vertex in the vertex adjacency cache, if it is built.
+    fn cache_new_vertex(&mut self) {
+        if let Some(vertex_to_he) = &mut self.vertex_to_he {
+            vertex_to_he.push(Vec::new());
+        }
+    }
+
+    /// Registers a new half-edge in the vertex adjacency cache, if it is built.
+    fn cache_new_he(&mut self, he_id: HalfEdgeIndex) {
+        if let Some(vertex_to_he) = &mut self.vertex_to_he {
+            vertex_to_he[self.he_to_vertex[he_id]].push(he_id);
+        }
+    }
+
+    /// Changes the origin vertex of an half-edge, keeping the vertex adjacency cache up to date.
+    fn set_he_origin(&mut self, he_id: HalfEdgeIndex, vertex_id: VertexIndex) {
+        if let Some(vertex_to_he) = &mut self.vertex_to_he {
+            vertex_to_he[self.he_to_vertex[he_id]].retain(|he| *he != he_id);
+            vertex_to_he[vertex_id].push(he_id);
+        }
+        self.he_to_vertex[he_id] = vertex_id;
+    }
+
+    /// Removes an half-edge by moving the last half-edge in its place and updating the links to it.
+    /// The removed half-edge must not be referenced by any other half-edge or parent anymore.
+    fn swap_remove_he(&mut self, he_id: HalfEdgeIndex) {
+        let last = HalfEdgeIndex(self.he_len() - 1);
+
+        if let Some(vertex_to_he) = &mut self.vertex_to_he {
+            vertex_to_he[self.he_to_vertex[he_id]].retain(|he| *he != he_id);
+            for he in vertex_to_he[self.he_to_vertex[last]].iter_mut() {
+                if *he == last {
+                    *he = he_id;
+                }
+            }
+        }
+
+        self.he_to_vertex.swap_remove(he_id.0);
+        self.he_to_twin.swap_remove(he_id.0);
+        self.he_to_next_he.swap_remove(he_id.0);
+        self.he_to_prev_he.swap_remove(he_id.0);
+        self.he_to_parent.swap_remove(he_id.0);
+
+        if he_id == last {
+            return;
+        }
+
+        let twin = self.he_to_twin[he_id];
+        self.he_to_twin[twin] = he_id;
+        let next = self.he_to_next_he[he_id];
+        self.he_to_prev_he[next] = he_id;
+        let prev = self.he_to_prev_he[he_id];
+        self.he_to_next_he[prev] = he_id;
+
+        for first_he in self.parent_to_first_he.iter_mut() {
+            if *first_he == last {
+                *first_he = he_id;
+            }
+        }
+    }
+
+    /// Removes a vertex by moving the last vertex in its place and updating the half-edges pointing to it.
+    /// The removed vertex must not be referenced by any half-edge anymore.
+    fn swap_remove_vertex(&mut self, vertex_id: VertexIndex) {
+        let last = VertexIndex(self.vertices_len() - 1);
+
+        self.vertices.swap_remove(vertex_id.0);
+        if let Some(vertex_to_he) = &mut self.vertex_to_he {
+            vertex_to_he.swap_remove(vertex_id.0);
+        }
+
+        if vertex_id == last {
+            return;
+        }
+
+        for vertex in self.he_to_vertex.iter_mut() {
+            if *vertex == last {
+                *vertex = vertex_id;
+            }
+        }
+    }
+
     /// Check that the mesh topology is valid.
     /// Used to confirm the topology before switching to an immutable mesh and for test purpose.
     ///
@@ -202,6 +547,36 @@ impl Base2DMesh {
             }
         }
 
+        // The next half-edge must start where the current one ends
+        for (i, next) in self.he_to_next_he.iter().enumerate() {
+            let he = HalfEdgeIndex(i);
+            if self.he_to_vertex[*next] != self.he_to_vertex[self.he_to_twin[he]] {
+                return Err(MeshError::NextVertexNotCorrect { he, he_next: *next });
+            }
+        }
+
+        if let Some(vertex_to_he) = &self.vertex_to_he {
+            if vertex_to_he.len() != self.vertices.len() {
+                return Err(MeshError::VertexAdjacencyNotCorrect {
+                    vertex: VertexIndex(vertex_to_he.len().min(self.vertices.len())),
+                });
+            }
+            // Scanned in the order of the half-edges, so each list is already sorted
+            let mut scanned = vec![Vec::new(); self.vertices_len()];
+            for (i, vertex) in self.he_to_vertex.iter().enumerate() {
+                scanned[*vertex].push(HalfEdgeIndex(i));
+            }
+            for (i, (cached, scanned)) in vertex_to_he.iter().zip(&scanned).enumerate() {
+                let mut cached = cached.clone();
+                cached.sort_by_key(|he| he.0);
+                if cached != *scanned {
+                    return Err(MeshError::VertexAdjacencyNotCorrect {
+                        vertex: VertexIndex(i),
+                    });
+                }
+            }
+        }
+
         // Might be redundant with the previous check
         for i in 0..self.he_to_vertex.len() {
             let origin = HalfEdgeIndex(i);
@@ -239,8 +614,61 @@ impl Base2DMesh {
             }
         }
 
+        // Each connected part of the domain has a characteristic of 2 minus its number of boundary loops,
+        // an isolated vertex counts as a part with a characteristic of 1
+        let (parts_len, isolated_len) = self.connected_parts_len();
+        let expected =
+            2 * parts_len as i64 - self.num_boundary_loops() as i64 - isolated_len as i64;
+        if self.euler_characteristic() != expected {
+            return Err(MeshError::UnexpectedEuler {
+                got: self.euler_characteristic(),
+                expected,
+            });
+        }
+
         Ok(())
     }
+
+    /// Computes the Euler characteristic V - E + F of the mesh, where only cells are counted as faces.
+    /// A connected domain has a characteristic of 1 minus its number of holes.
+    pub fn euler_characteristic(&self) -> i64 {
+        let edges_len = (0..self.he_len())
+            .filter(|i| *i < self.he_to_twin[HalfEdgeIndex(*i)].0)
+            .count();
+        self.vertices_len() as i64 - edges_len as i64 + self.cells_len() as i64
+    }
+
+    /// Counts the connected parts of the mesh linked by edges, and among them the isolated vertices.
+    fn connected_parts_len(&self) -> (usize, usize) {
+        // Union-find on the vertices with path halving
+        let mut vertex_to_root: Vec<usize> = (0..self.vertices_len()).collect();
+        let root = |vertex_to_root: &mut Vec<usize>, mut vertex: usize| {
+            while vertex_to_root[vertex] != vertex {
+                vertex_to_root[vertex] = vertex_to_root[vertex_to_root[vertex]];
+                vertex = vertex_to_root[vertex];
+            }
+            vertex
+        };
+
+        let mut isolated = vec![true; self.vertices_len()];
+        for (i, twin) in self.he_to_twin.iter().enumerate() {
+            let [start, end] = [
+                self.he_to_vertex[HalfEdgeIndex(i)],
+                self.he_to_vertex[*twin],
+            ];
+            isolated[start.0] = false;
+            let (start, end) = (
+                root(&mut vertex_to_root, start.0),
+                root(&mut vertex_to_root, end.0),
+            );
+            vertex_to_root[start] = end;
+        }
+
+        let parts_len = (0..self.vertices_len())
+            .filter(|vertex| vertex_to_root[*vertex] == *vertex)
+            .count();
+        (parts_len, isolated.into_iter().filter(|isolated| *isolated).count())
+    }
     
     
     /// Written by chatGPT, proper export function will be made later
@@ -280,8 +708,157 @@ impl Base2DMesh {
         Ok(())
 
     }
+
+    /// Exports the cells of the mesh as polygons in the VTK XML UnstructuredGrid format (.vtu).
+    /// Boundary parents are not exported, the index of the parent of each cell is written as cell data.
+    pub fn export_vtu(&self, filename: &str) -> io::Result<()> {
+        let cells: Vec<ParentIndex> = (0..self.parents_len())
+            .map(ParentIndex)
+            .filter(|parent| self.parents[*parent] == Parent::Cell)
+            .collect();
+        let cells_vertices: Vec<Vec<VertexIndex>> = cells
+            .iter()
+            .map(|cell| self.vertices_from_parent(*cell))
+            .collect();
+
+        let mut file = File::create(filename)?;
+
+        writeln!(file, "<?xml version=\"1.0\"?>")?;
+        writeln!(
+            file,
+            "<VTKFile type=\"UnstructuredGrid\" version=\"0.1\" byte_order=\"LittleEndian\">"
+        )?;
+        writeln!(file, "  <UnstructuredGrid>")?;
+        writeln!(
+            file,
+            "    <Piece NumberOfPoints=\"{}\" NumberOfCells=\"{}\">",
+            self.vertices_len(),
+            cells.len()
+        )?;
+
+        writeln!(file, "      <Points>")?;
+        writeln!(
+            file,
+            "        <DataArray type=\"Float64\" NumberOfComponents=\"3\" format=\"ascii\">"
+        )?;
+        for vertex in &self.vertices {
+            writeln!(file, "          {} {} 0.0", vertex.x, vertex.y)?;
+        }
+        writeln!(file, "        </DataArray>")?;
+        writeln!(file, "      </Points>")?;
+
+        writeln!(file, "      <Cells>")?;
+        writeln!(
+            file,
+            "        <DataArray type=\"Int64\" Name=\"connectivity\" format=\"ascii\">"
+        )?;
+        for cell_vertices in &cells_vertices {
+            let line: Vec<String> = cell_vertices.iter().map(|v| v.to_string()).collect();
+            writeln!(file, "          {}", line.join(" "))?;
+        }
+        writeln!(file, "        </DataArray>")?;
+        writeln!(
+            file,
+            "        <DataArray type=\"Int64\" Name=\"offsets\" format=\"ascii\">"
+        )?;
+        let mut offset = 0;
+        for cell_vertices in &cells_vertices {
+            offset += cell_vertices.len();
+            writeln!(file, "          {}", offset)?;
+        }
+        writeln!(file, "        </DataArray>")?;
+        writeln!(
+            file,
+            "        <DataArray type=\"UInt8\" Name=\"types\" format=\"ascii\">"
+        )?;
+        // VTK_POLYGON
+        for _ in &cells {
+            writeln!(file, "          7")?;
+        }
+        writeln!(file, "        </DataArray>")?;
+        writeln!(file, "      </Cells>")?;
+
+        writeln!(file, "      <CellData Scalars=\"parent\">")?;
+        writeln!(
+            file,
+            "        <DataArray type=\"Int64\" Name=\"parent\" format=\"ascii\">"
+        )?;
+        for cell in &cells {
+            writeln!(file, "          {}", cell)?;
+        }
+        writeln!(file, "        </DataArray>")?;
+        writeln!(file, "      </CellData>")?;
+
+        writeln!(file, "    </Piece>")?;
+        writeln!(file, "  </UnstructuredGrid>")?;
+        writeln!(file, "</VTKFile>")?;
+
+        Ok(())
+    }
+
+    /// Exports the cells of the mesh in the Wavefront OBJ format, with z set to 0.
+    /// Boundary parents are not exported, each cell is written as a face with its counter-clockwise winding.
+    pub fn export_obj(&self, filename: &str) -> io::Result<()> {
+        let mut file = File::create(filename)?;
+
+        for vertex in &self.vertices {
+            writeln!(file, "v {} {} 0", vertex.x, vertex.y)?;
+        }
+
+        for i in 0..self.parents_len() {
+            let parent = ParentIndex(i);
+            if self.parents[parent] != Parent::Cell {
+                continue;
+            }
+            // OBJ indices start at 1
+            let face: Vec<String> = self
+                .vertices_from_parent(parent)
+                .iter()
+                .map(|vertex| (vertex.0 + 1).to_string())
+                .collect();
+            writeln!(file, "f {}", face.join(" "))?;
+        }
+
+        Ok(())
+    }
 }
 
+/// Compares the meshes without the vertex adjacency cache, which does not change the mesh itself.
+impl PartialEq for Base2DMesh {
+    fn eq(&self, other: &Self) -> bool {
+        self.he_to_vertex == other.he_to_vertex
+            && self.he_to_twin == other.he_to_twin
+            && self.he_to_next_he == other.he_to_next_he
+            && self.he_to_prev_he == other.he_to_prev_he
+            && self.he_to_parent == other.he_to_parent
+            && self.vertices == other.vertices
+            && self.parents == other.parents
+            && self.parent_to_first_he == other.parent_to_first_he
+    }
+}
+
+/// Iterator over the half-edges of a parent, following the next half-edges from the first one.
+/// Created by ```Base2DMesh::he_iter_from_parent```.
+#[derive(Clone, Debug)]
+pub struct ParentHalfEdges<'a> {
+    mesh: &'a Base2DMesh,
+    first_he: HalfEdgeIndex,
+    current_he: Option<HalfEdgeIndex>,
+}
+
+impl Iterator for ParentHalfEdges<'_> {
+    type Item = HalfEdgeIndex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let he = self.current_he?;
+        let next_he = self.mesh.he_to_next_he[he];
+        self.current_he = (next_he != self.first_he).then_some(next_he);
+        Some(he)
+    }
+}
+
+impl FusedIterator for ParentHalfEdges<'_> {}
+
 /// Gives access to modifications from Base2DMesh
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct Modifiable2DMesh(pub Base2DMesh);
@@ -293,7 +870,8 @@ pub struct Safe2DMesh(pub Base2DMesh);
 impl Modifiable2DMesh {
     /// Creates a mesh with only boundaries defined.
     /// Expects the edges to be sorted (the next edge is starting with the same vertex as the last from the previous edge)
-    /// Parents designates the parent at the right side from an edge
+    /// Parents designates the boundary parent outside of the domain along an edge.
+    /// ```winding``` gives the orientation of the input polygon, clockwise input is reversed so that the cell is always built counter-clockwise.
     ///
     /// Failing to comply with those invariants might result in unexpected behaviours.
     /// This function is done in a simple version for testing purpose, but will be changed in the future.
@@ -306,9 +884,19 @@ impl Modifiable2DMesh {
         vertices: Vec<Point2<f64>>,
         edge_to_vertices_and_parent: Vec<(VertexIndex, VertexIndex, ParentIndex)>,
         parents: Vec<Parent>,
+        winding: Winding,
     ) -> Self {
         let mut parents = parents;
 
+        let edge_to_vertices_and_parent = match winding {
+            Winding::Ccw => edge_to_vertices_and_parent,
+            Winding::Cw => edge_to_vertices_and_parent
+                .into_iter()
+                .rev()
+                .map(|(start, end, parent)| (end, start, parent))
+                .collect(),
+        };
+
         let mut he_to_vertex =
             Vec::<VertexIndex>::with_capacity(edge_to_vertices_and_parent.len() * 2);
         let mut he_to_twin =
@@ -389,9 +977,286 @@ impl Modifiable2DMesh {
 
             parents,
             parent_to_first_he,
+
+            vertex_to_he: None,
         })
     }
 
+    /// Creates a mesh from cells given as lists of vertices.
+    /// Clockwise cells are reversed so that all cells are built counter-clockwise.
+    /// Edges shared by two cells are twinned, the remaining edges are gathered in closed loops,
+    /// each loop becoming a new parent of type ```boundary```.
+    ///
+    /// Fails if a cell has less than 3 vertices or the same vertex twice,
+    /// if an edge is shared by more than two cells or if the boundary touches itself at a vertex.
+    pub fn new_from_cells(
+        vertices: Vec<Point2<f64>>,
+        cells: &[Vec<VertexIndex>],
+        boundary: Boundary,
+    ) -> Result<Self, MeshError> {
+        let mut mesh = Base2DMesh {
+            vertices,
+            ..Default::default()
+        };
+        let mut edges = HashMap::new();
+
+        for cell in cells {
+            for vertex in cell {
+                if *vertex >= VertexIndex(mesh.vertices_len()) {
+                    return Err(MeshError::VertexIndexOutOfBound {
+                        got: *vertex,
+                        len: mesh.vertices_len(),
+                    });
+                }
+            }
+            if (cell.len() < 3) | (1..cell.len()).any(|i| cell[..i].contains(&cell[i])) {
+                return Err(MeshError::DegenerateCell {
+                    parent: ParentIndex(mesh.parents_len()),
+                });
+            }
+
+            let points: Vec<Point2<f64>> =
+                cell.iter().map(|vertex| mesh.vertices[*vertex]).collect();
+            let cell: Vec<VertexIndex> = if geometry::signed_polygon_area(&points) < 0.0 {
+                cell.iter().rev().copied().collect()
+            } else {
+                cell.clone()
+            };
+
+            let parent = ParentIndex(mesh.parents_len());
+            let first_he = mesh.he_len();
+            mesh.parents.push(Parent::Cell);
+            mesh.parent_to_first_he.push(HalfEdgeIndex(first_he));
+            for i in 0..cell.len() {
+                let he = HalfEdgeIndex(first_he + i);
+                if edges
+                    .insert((cell[i].0, cell[(i + 1) % cell.len()].0), he)
+                    .is_some()
+                {
+                    return Err(MeshError::NotManifold { vertex: cell[i] });
+                }
+                mesh.he_to_vertex.push(cell[i]);
+                mesh.he_to_twin.push(HalfEdgeIndex(usize::MAX));
+                mesh.he_to_next_he
+                    .push(HalfEdgeIndex(first_he + (i + 1) % cell.len()));
+                mesh.he_to_prev_he
+                    .push(HalfEdgeIndex(first_he + (i + cell.len() - 1) % cell.len()));
+                mesh.he_to_parent.push(parent);
+            }
+        }
+
+        // Twins, boundary half-edges are created where there is none
+        let mut boundary_from_vertex = HashMap::new();
+        for i in 0..mesh.he_len() {
+            let he = HalfEdgeIndex(i);
+            let [start, end] = [
+                mesh.he_to_vertex[he],
+                mesh.he_to_vertex[mesh.he_to_next_he[he]],
+            ];
+            let twin = match edges.get(&(end.0, start.0)) {
+                Some(twin) => *twin,
+                None => {
+                    let twin = HalfEdgeIndex(mesh.he_len());
+                    mesh.he_to_vertex.push(end);
+                    mesh.he_to_twin.push(he);
+                    mesh.he_to_next_he.push(HalfEdgeIndex(usize::MAX));
+                    mesh.he_to_prev_he.push(HalfEdgeIndex(usize::MAX));
+                    mesh.he_to_parent.push(ParentIndex(usize::MAX));
+                    if boundary_from_vertex.insert(end.0, twin).is_some() {
+                        return Err(MeshError::NotManifold { vertex: end });
+                    }
+                    twin
+                }
+            };
+            mesh.he_to_twin[he] = twin;
+        }
+
+        // A boundary half-edge ends where the twin of the next one starts
+        for he in boundary_from_vertex.values() {
+            let next = boundary_from_vertex[&mesh.he_to_vertex[mesh.he_to_twin[*he]].0];
+            mesh.he_to_next_he[*he] = next;
+            mesh.he_to_prev_he[next] = *he;
+        }
+
+        for i in 0..mesh.he_len() {
+            let he = HalfEdgeIndex(i);
+            if mesh.he_to_parent[he] != ParentIndex(usize::MAX) {
+                continue;
+            }
+            let parent = ParentIndex(mesh.parents_len());
+            mesh.parents.push(Parent::Boundary(boundary.clone()));
+            mesh.parent_to_first_he.push(he);
+            let mut current_he = he;
+            while mesh.he_to_parent[current_he] != parent {
+                mesh.he_to_parent[current_he] = parent;
+                current_he = mesh.he_to_next_he[current_he];
+            }
+        }
+
+        Ok(Modifiable2DMesh(mesh))
+    }
+
+    /// Reads a mesh from an ASCII file in the Gmsh 2.2 format.
+    /// Triangles and quadrangles become cells, the z coordinate is ignored.
+    /// Points and lines are skipped, any other element (e.g. second order triangles) is an error.
+    ///
+    /// Boundaries are deduced from the cells, each boundary loop gets a parent with ```Boundary::None``` to be set afterwards.
+    /// As a boundary parent has to be a closed loop, the physical groups of the line elements are returned instead:
+    /// each boundary half-edge lying on a line with a physical tag is given with this tag, in the order of the half-edges.
+    pub fn from_gmsh(filename: &str) -> Result<(Self, Vec<(HalfEdgeIndex, usize)>), MeshError> {
+        let content =
+            std::fs::read_to_string(filename).map_err(|_| MeshError::FileNotReadable {
+                filename: filename.to_string(),
+            })?;
+        let lines: Vec<&str> = content.lines().map(str::trim).collect();
+
+        let mut vertices = Vec::new();
+        let mut tag_to_vertex = HashMap::new();
+        let mut cells = Vec::new();
+        let mut edge_to_tag = HashMap::new();
+
+        let mut current = 0;
+        while current < lines.len() {
+            current += 1;
+            match lines[current - 1] {
+                "$MeshFormat" => {
+                    // Version, file type (0 for ASCII) and data size
+                    let format: Vec<f64> = next_gmsh_values(&lines, &mut current)?;
+                    if format.len() != 3 || format[0].floor() != 2.0 || format[1] != 0.0 {
+                        return Err(MeshError::ParsingError { line: current });
+                    }
+                }
+                "$Nodes" => {
+                    let count = next_gmsh_count(&lines, &mut current)?;
+                    for _ in 0..count {
+                        // Tag and coordinates, tags are integers so they are read exactly as floats
+                        let values: Vec<f64> = next_gmsh_values(&lines, &mut current)?;
+                        if values.len() != 4 {
+                            return Err(MeshError::ParsingError { line: current });
+                        }
+                        tag_to_vertex.insert(values[0] as usize, VertexIndex(vertices.len()));
+                        vertices.push(Point2::new(values[1], values[2]));
+                    }
+                }
+                "$Elements" => {
+                    let count = next_gmsh_count(&lines, &mut current)?;
+                    for _ in 0..count {
+                        // Tag, type, number of tags, tags and nodes
+                        let values: Vec<usize> = next_gmsh_values(&lines, &mut current)?;
+                        let start = values
+                            .get(2)
+                            .and_then(|tags_len| 3usize.checked_add(*tags_len))
+                            .filter(|start| *start <= values.len())
+                            .ok_or(MeshError::ParsingError { line: current })?;
+                        let (tags, nodes) = (&values[3..start], &values[start..]);
+                        // Points are skipped, lines only give their physical tag (the first one, 0 if none)
+                        // and other elements are not supported
+                        let nodes_len = match values[1] {
+                            1 => 2,
+                            2 | 8 => 3,
+                            3 => 4,
+                            15 => continue,
+                            _ => return Err(MeshError::ParsingError { line: current }),
+                        };
+                        if nodes.len() != nodes_len {
+                            return Err(MeshError::ParsingError { line: current });
+                        }
+                        let element = nodes
+                            .iter()
+                            .map(|tag| {
+                                tag_to_vertex
+                                    .get(tag)
+                                    .copied()
+                                    .ok_or(MeshError::ParsingError { line: current })
+                            })
+                            .collect::<Result<Vec<VertexIndex>, MeshError>>()?;
+                        match (values[1], tags.first()) {
+                            (2 | 3, _) => cells.push(element),
+                            (_, Some(&physical)) if physical != 0 => {
+                                // The end vertices of a line are its first two nodes
+                                let edge = (
+                                    element[0].0.min(element[1].0),
+                                    element[0].0.max(element[1].0),
+                                );
+                                edge_to_tag.insert(edge, physical);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mesh = Self::new_from_cells(vertices, &cells, Boundary::None)?;
+        let tags = mesh
+            .0
+            .boundary_half_edges()
+            .into_iter()
+            .filter_map(|he| {
+                let [start, end] = mesh.0.vertices_from_he(he);
+                edge_to_tag
+                    .get(&(start.0.min(end.0), start.0.max(end.0)))
+                    .map(|physical| (he, *physical))
+            })
+            .collect();
+
+        Ok((mesh, tags))
+    }
+
+    /// Reads a mesh from a Wavefront OBJ file, only the vertices and the faces are used.
+    /// Each face becomes a cell and the z coordinate is ignored, see ```new_from_cells```.
+    /// Each boundary loop gets a parent with ```Boundary::None``` to be set afterwards.
+    pub fn from_obj(filename: &str) -> Result<Self, MeshError> {
+        let content =
+            std::fs::read_to_string(filename).map_err(|_| MeshError::FileNotReadable {
+                filename: filename.to_string(),
+            })?;
+
+        let mut vertices = Vec::new();
+        let mut cells = Vec::new();
+
+        for (i, line) in content.lines().enumerate() {
+            let error = MeshError::ParsingError { line: i + 1 };
+            let mut values = line.split_whitespace();
+            match values.next() {
+                Some("v") => {
+                    let coordinates = values
+                        .take(2)
+                        .map(|value| value.parse::<f64>().map_err(|_| error.clone()))
+                        .collect::<Result<Vec<f64>, MeshError>>()?;
+                    if coordinates.len() != 2 {
+                        return Err(error);
+                    }
+                    vertices.push(Point2::new(coordinates[0], coordinates[1]));
+                }
+                Some("f") => {
+                    let cell = values
+                        .map(|value| {
+                            // Texture and normal indices are ignored, negative indices are relative to the end
+                            let index = value
+                                .split('/')
+                                .next()
+                                .and_then(|index| index.parse::<isize>().ok())
+                                .ok_or(error.clone())?;
+                            if index > 0 {
+                                Ok(VertexIndex(index as usize - 1))
+                            } else if index < 0 && index.unsigned_abs() <= vertices.len() {
+                                Ok(VertexIndex(vertices.len() - index.unsigned_abs()))
+                            } else {
+                                Err(error.clone())
+                            }
+                        })
+                        .collect::<Result<Vec<VertexIndex>, MeshError>>()?;
+                    cells.push(cell);
+                }
+                _ => {}
+            }
+        }
+
+        Self::new_from_cells(vertices, &cells, Boundary::None)
+    }
+
     pub fn validate_topology(self) -> Result<Safe2DMesh, MeshError> {
         self.0.check_mesh()?;
         Ok(Safe2DMesh(self.0))
@@ -448,6 +1313,9 @@ impl Modifiable2DMesh {
 
         self.0.he_to_vertex.push(new_vertex_id);
         self.0.he_to_vertex.push(new_vertex_id);
+        self.0.cache_new_vertex();
+        self.0.cache_new_he(new_he_ids.0);
+        self.0.cache_new_he(new_he_ids.1);
 
         self.0.he_to_twin.push(he_ids.1);
         self.0.he_to_twin.push(he_ids.0);
@@ -499,22 +1367,25 @@ impl Modifiable2DMesh {
             });
         }
 
-        for (i, twin) in self.0.he_to_twin.iter().enumerate() {
-            let he = HalfEdgeIndex(i);
-            if (self.0.he_to_vertex[he] == vertices.0) & (self.0.he_to_vertex[*twin] == vertices.1)
-            {
-                return Err(MeshError::AlreadyExists);
-            }
+        // Only the half-edges starting from the first vertex can already link the two vertices
+        let he_to_vertices = self.0.he_from_vertex(vertices.0);
+        if he_to_vertices
+            .iter()
+            .any(|he| self.0.he_to_vertex[self.0.he_to_twin[*he]] == vertices.1)
+        {
+            return Err(MeshError::AlreadyExists);
         }
 
+        // The first new half-edge closes the new cell (going from vertices.1 to vertices.0),
+        // the second one stays in the original parent (going from vertices.0 to vertices.1)
         let new_he = self.0.he_len();
-        self.0.he_to_vertex.push(vertices.0);
         self.0.he_to_vertex.push(vertices.1);
+        self.0.he_to_vertex.push(vertices.0);
+        self.0.cache_new_he(HalfEdgeIndex(new_he));
+        self.0.cache_new_he(HalfEdgeIndex(new_he + 1));
         self.0.he_to_twin.push(HalfEdgeIndex(new_he + 1));
         self.0.he_to_twin.push(HalfEdgeIndex(new_he));
 
-        let he_to_vertices = self.0.he_from_vertex(vertices.0);
-
         let new_cell = self.0.parents_len();
         self.0.parents.push(Parent::Cell);
         self.0.he_to_parent.push(ParentIndex(new_cell));
@@ -573,6 +1444,91 @@ impl Modifiable2DMesh {
         Ok(())
     }
     
+    /// Safe version of ```add_edge_between_vertices```.
+    /// Checks that the parent is a cell containing both vertices, that the new edge does not cross any edge of the parent
+    /// and that it lies inside the parent before adding it.
+    pub fn try_add_edge_between_vertices(
+        &mut self,
+        vertices: (VertexIndex, VertexIndex),
+        parent: ParentIndex,
+    ) -> Result<(), MeshError> {
+        for vertex in [vertices.0, vertices.1] {
+            if vertex >= VertexIndex(self.0.vertices_len()) {
+                return Err(MeshError::VertexIndexOutOfBound {
+                    got: vertex,
+                    len: self.0.vertices_len(),
+                });
+            }
+        }
+        if parent >= ParentIndex(self.0.parents_len()) {
+            return Err(MeshError::ParentIndexOutOfBound {
+                got: parent,
+                len: self.0.parents_len(),
+            });
+        }
+        if vertices.0 == vertices.1 {
+            return Err(MeshError::SelfLoopEdge { vertex: vertices.0 });
+        }
+        // The inside of a boundary loop is the outside of the domain
+        if self.0.parents[parent] != Parent::Cell {
+            return Err(MeshError::ParentIsNotACell { parent });
+        }
+        let parent_vertices = self.0.vertices_from_parent(parent);
+        for vertex in [vertices.0, vertices.1] {
+            if !parent_vertices.contains(&vertex) {
+                return Err(MeshError::ParentDoesNotContainVertex { vertex, parent });
+            }
+        }
+
+        let new_edge = (self.0.vertices[vertices.0], self.0.vertices[vertices.1]);
+        for he in self.0.he_from_parent(parent) {
+            let [start, end] = self.0.vertices_from_he(he);
+            if (start == vertices.0 && end == vertices.1)
+                | (start == vertices.1 && end == vertices.0)
+            {
+                return Err(MeshError::AlreadyExists);
+            }
+            // Edges sharing a vertex with the new edge touch it by construction,
+            // they only overlap with it if they are collinear and go in the same direction
+            if let Some(shared) = [vertices.0, vertices.1]
+                .into_iter()
+                .find(|vertex| [start, end].contains(vertex))
+            {
+                let other = if shared == start { end } else { start };
+                let new_other = if shared == vertices.0 {
+                    vertices.1
+                } else {
+                    vertices.0
+                };
+                let edge = self.0.vertices[other] - self.0.vertices[shared];
+                let new_edge = self.0.vertices[new_other] - self.0.vertices[shared];
+                if (geometry::cross_2d(edge, new_edge).abs()
+                    <= 1e-12 * edge.norm() * new_edge.norm())
+                    && (edge.dot(&new_edge) > 0.0)
+                {
+                    return Err(MeshError::EdgeWouldCross { he });
+                }
+                continue;
+            }
+            let edge = (self.0.vertices[start], self.0.vertices[end]);
+            if geometry::segments_intersect(new_edge, edge) {
+                return Err(MeshError::EdgeWouldCross { he });
+            }
+        }
+
+        // Without crossing, the edge is either fully inside or fully outside of the parent
+        let polygon: Vec<Point2<f64>> = parent_vertices
+            .into_iter()
+            .map(|vertex| self.0.vertices[vertex])
+            .collect();
+        if geometry::winding_number(nalgebra::center(&new_edge.0, &new_edge.1), &polygon) == 0 {
+            return Err(MeshError::EdgeOutsideParent { parent });
+        }
+
+        // The edge does not cross the parent boundary
+        unsafe { self.add_edge_between_vertices(vertices, parent) }
+    }
+
     /// Creates a triangle
     /// 
     /// # Safety
@@ -596,5 +1552,540 @@ impl Modifiable2DMesh {
         
         Ok(())
     }
-    
+
+    /// Splits a quad cell into two triangles by adding one of its diagonals.
+    /// The diagonal links the first and third vertices of the cell, or the second and fourth ones if ```diagonal``` is true.
+    ///
+    /// Returns the index of the newly created triangle.
+    pub fn split_quad(
+        &mut self,
+        parent: ParentIndex,
+        diagonal: bool,
+    ) -> Result<ParentIndex, MeshError> {
+        if parent >= ParentIndex(self.0.parents_len()) {
+            return Err(MeshError::ParentIndexOutOfBound {
+                got: parent,
+                len: self.0.parents_len(),
+            });
+        }
+
+        if self.0.parents[parent] != Parent::Cell {
+            return Err(MeshError::ParentIsNotACell { parent });
+        }
+
+        let vertices = self.0.vertices_from_parent(parent);
+        if vertices.len() != 4 {
+            return Err(MeshError::WrongEdgeCount {
+                parent,
+                expected: 4,
+                got: vertices.len(),
+            });
+        }
+
+        let diagonal = if diagonal {
+            (vertices[1], vertices[3], vertices[0], vertices[2])
+        } else {
+            (vertices[0], vertices[2], vertices[1], vertices[3])
+        };
+
+        // On a non-convex quad one of the diagonals lies outside of the cell,
+        // it is the case when the two other vertices are on the same side of the diagonal
+        let origin = self.0.vertices[diagonal.0];
+        let direction = self.0.vertices[diagonal.1] - origin;
+        let side_1 = direction.perp(&(self.0.vertices[diagonal.2] - origin));
+        let side_2 = direction.perp(&(self.0.vertices[diagonal.3] - origin));
+        if side_1 * side_2 >= 0.0 {
+            return Err(MeshError::DiagonalOutsideQuad { parent });
+        }
+
+        let new_parent = ParentIndex(self.0.parents_len());
+        // The diagonal is inside the quad so it cannot cross any edge
+        unsafe {
+            self.add_edge_between_vertices((diagonal.0, diagonal.1), parent)?;
+        }
+
+        Ok(new_parent)
+    }
+
+    /// Merges consecutive boundary edges when the turning angle between them is below ```angle_tolerance``` (in radians).
+    /// The vertex between the two edges is removed, which is only done if no interior edge is connected to it.
+    /// Removed vertices and half-edges are replaced by the last ones, so the indices of the remaining vertices and half-edges can change.
+    ///
+    /// Returns the number of merged edges.
+    pub fn simplify_boundary(&mut self, angle_tolerance: f64) -> Result<usize, MeshError> {
+        if !(0.0..std::f64::consts::PI).contains(&angle_tolerance) {
+            return Err(MeshError::WrongFloatValue {
+                got: angle_tolerance,
+                expected: (0.0, std::f64::consts::PI),
+            });
+        }
+
+        let mut valences = vec![0; self.0.vertices_len()];
+        for vertex in &self.0.he_to_vertex {
+            valences[vertex.0] += 1;
+        }
+        let mut parent_lens = vec![0; self.0.parents_len()];
+        for parent in &self.0.he_to_parent {
+            parent_lens[parent.0] += 1;
+        }
+
+        // Half-edges and vertices are only removed at the end so that the indices stay valid meanwhile
+        let mut removed_he = vec![false; self.0.he_len()];
+        let mut removed_vertices = Vec::new();
+
+        // A merge changes the edge before the removed vertex, so both its ends are checked again
+        let mut candidates = self.0.boundary_half_edges();
+        while let Some(he_out) = candidates.pop() {
+            if removed_he[he_out.0] {
+                continue;
+            }
+
+            let vertex = self.0.he_to_vertex[he_out];
+            let he_in = self.0.he_to_prev_he[he_out];
+            let twin_in = self.0.he_to_twin[he_out];
+            let twin_out = self.0.he_to_twin[he_in];
+            let boundary = self.0.he_to_parent[he_out];
+            let cell = self.0.he_to_parent[twin_in];
+
+            if (valences[vertex.0] != 2)
+                | (parent_lens[boundary.0] <= 3)
+                | (parent_lens[cell.0] <= 3)
+            {
+                continue;
+            }
+
+            let [start, middle] = self.0.vertices_from_he(he_in);
+            let end = self.0.he_to_vertex[twin_in];
+            let edge_in = self.0.vertices[middle] - self.0.vertices[start];
+            let edge_out = self.0.vertices[end] - self.0.vertices[middle];
+
+            if geometry::angle_between(edge_in, edge_out).abs() >= angle_tolerance {
+                continue;
+            }
+
+            // he_in and the twin of he_out are extended over the removed vertex
+            let next = self.0.he_to_next_he[he_out];
+            self.0.he_to_next_he[he_in] = next;
+            self.0.he_to_prev_he[next] = he_in;
+            let next = self.0.he_to_next_he[twin_out];
+            self.0.he_to_next_he[twin_in] = next;
+            self.0.he_to_prev_he[next] = twin_in;
+
+            self.0.he_to_twin[he_in] = twin_in;
+            self.0.he_to_twin[twin_in] = he_in;
+
+            if self.0.parent_to_first_he[boundary] == he_out {
+                self.0.parent_to_first_he[boundary] = he_in;
+            }
+            if self.0.parent_to_first_he[cell] == twin_out {
+                self.0.parent_to_first_he[cell] = twin_in;
+            }
+
+            removed_he[he_out.0] = true;
+            removed_he[twin_out.0] = true;
+            removed_vertices.push(vertex);
+            parent_lens[boundary.0] -= 1;
+            parent_lens[cell.0] -= 1;
+
+            candidates.push(he_in);
+            candidates.push(self.0.he_to_next_he[he_in]);
+        }
+
+        // The highest indices are removed first so that the moved ones are never removed afterwards
+        for he in (0..self.0.he_len()).rev().filter(|he| removed_he[*he]) {
+            self.0.swap_remove_he(HalfEdgeIndex(he));
+        }
+        removed_vertices.sort_by_key(|vertex| std::cmp::Reverse(vertex.0));
+        for vertex in &removed_vertices {
+            self.0.swap_remove_vertex(*vertex);
+        }
+
+        Ok(removed_vertices.len())
+    }
+
+    /// Refines the triangles having an angle smaller than ```min_angle``` (in radians) until there is none left, in the manner of Ruppert's algorithm.
+    /// The mesh is first made Delaunay by flipping edges, then the circumcenter of each bad triangle is inserted and the Delaunay property restored.
+    /// When the circumcenter encroaches a boundary edge (lies in the circle having this edge as diameter), the boundary edge is split in its middle instead.
+    /// If the circumcenter is outside of the domain or on an edge, the longest edge of the triangle is split in its middle.
+    ///
+    /// As in Ruppert's algorithm, termination is only expected for ```min_angle``` up to about 20 degrees and when the angles between boundary edges are not smaller,
+    /// hence the ```max_iterations``` limit.
+    ///
+    /// Returns the number of inserted vertices.
+    pub fn enforce_min_angle(
+        &mut self,
+        min_angle: f64,
+        max_iterations: usize,
+    ) -> Result<usize, MeshError> {
+        let mut insertions = 0;
+        self.make_delaunay()?;
+
+        loop {
+            let bad_cell = (0..self.0.parents_len()).map(ParentIndex).find(|parent| {
+                (self.0.parents[*parent] == Parent::Cell)
+                    && (self.0.he_iter_from_parent(*parent).count() == 3)
+                    && (self.0.min_angle_from_parent(*parent) < min_angle)
+            });
+
+            let Some(bad_cell) = bad_cell else {
+                return Ok(insertions);
+            };
+
+            if insertions >= max_iterations {
+                return Err(MeshError::MaxIterationReached { max_iterations });
+            }
+
+            let [a, b, c] = match self.0.vertices_from_parent(bad_cell)[..] {
+                [a, b, c] => [a, b, c].map(|vertex| self.0.vertices[vertex]),
+                _ => unreachable!(),
+            };
+            let epsilon = 1e-12 * ((b - a).norm_squared() + (c - a).norm_squared());
+            let circumcenter = geometry::circumcenter(a, b, c, epsilon);
+
+            let encroached_he = circumcenter.and_then(|circumcenter| {
+                self.0.boundary_half_edges().into_iter().find(|he| {
+                    let [start, end] = self.0.vertices_from_he(*he);
+                    (self.0.vertices[start] - circumcenter)
+                        .dot(&(self.0.vertices[end] - circumcenter))
+                        < 0.0
+                })
+            });
+            let containing_cell = circumcenter.and_then(|circumcenter| {
+                (0..self.0.parents_len()).map(ParentIndex).find(|parent| {
+                    let polygon: Vec<Point2<f64>> = self
+                        .0
+                        .vertices_from_parent(*parent)
+                        .into_iter()
+                        .map(|vertex| self.0.vertices[vertex])
+                        .collect();
+                    (self.0.parents[*parent] == Parent::Cell)
+                        && (geometry::winding_number(circumcenter, &polygon) != 0)
+                })
+            });
+
+            match (circumcenter, encroached_he, containing_cell) {
+                (_, Some(he), _) => self.bisect_edge(he)?,
+                (Some(circumcenter), None, Some(cell)) => {
+                    match self.insert_vertex_in_cell(cell, circumcenter) {
+                        Ok(_) => {}
+                        // The circumcenter is on an edge of the cell
+                        Err(MeshError::NotStarShaped { .. }) => {
+                            self.bisect_edge(self.longest_he_from_parent(bad_cell))?
+                        }
+                        Err(error) => return Err(error),
+                    }
+                }
+                _ => self.bisect_edge(self.longest_he_from_parent(bad_cell))?,
+            }
+
+            self.make_delaunay()?;
+            insertions += 1;
+        }
+    }
+
+    /// Gets the longest half-edge of a parent.
+    fn longest_he_from_parent(&self, parent_id: ParentIndex) -> HalfEdgeIndex {
+        self.0
+            .he_iter_from_parent(parent_id)
+            .max_by(|a, b| {
+                let length = |he| {
+                    let [start, end] = self.0.vertices_from_he(he);
+                    (self.0.vertices[end] - self.0.vertices[start]).norm()
+                };
+                length(*a).total_cmp(&length(*b))
+            })
+            .unwrap()
+    }
+
+    /// Splits an edge in its middle and links the middle to the opposite vertex of the triangle cells on both sides,
+    /// so that triangles stay triangles.
+    fn bisect_edge(&mut self, he_id: HalfEdgeIndex) -> Result<(), MeshError> {
+        // Opposite vertices of the triangles on both sides, they must be found before splitting
+        let sides: Vec<(ParentIndex, VertexIndex)> = [he_id, self.0.twin_from_he(he_id)]
+            .into_iter()
+            .map(|he| (self.0.parent_from_he(he), he))
+            .filter(|(parent, _)| {
+                (self.0.parents[*parent] == Parent::Cell)
+                    && (self.0.he_iter_from_parent(*parent).count() == 3)
+            })
+            .map(|(parent, he)| (parent, self.0.he_to_vertex[self.0.prev_he_from_he(he)]))
+            .collect();
+
+        let new_vertex = VertexIndex(self.0.vertices_len());
+        self.split_edge(he_id, 0.5)?;
+
+        for (parent, opposite_vertex) in sides {
+            // The median of a triangle cannot cross any of its edges
+            unsafe {
+                self.add_edge_between_vertices((new_vertex, opposite_vertex), parent)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Flips the edges between triangle cells until all of them respect the Delaunay criterion, see ```delaunay_flip```.
+    ///
+    /// Returns the number of flipped edges.
+    fn make_delaunay(&mut self) -> Result<usize, MeshError> {
+        let mut flips = 0;
+
+        loop {
+            let mut flipped = false;
+            for i in 0..self.0.he_len() {
+                let he = HalfEdgeIndex(i);
+                let both_triangles = [he, self.0.he_to_twin[he]].into_iter().all(|he| {
+                    let parent = self.0.he_to_parent[he];
+                    (self.0.parents[parent] == Parent::Cell)
+                        && (self.0.he_iter_from_parent(parent).count() == 3)
+                });
+                if both_triangles && self.delaunay_flip(he)? {
+                    flipped = true;
+                    flips += 1;
+                }
+            }
+            if !flipped {
+                return Ok(flips);
+            }
+        }
+    }
+
+    /// Inserts a new vertex inside a cell and links it to every vertex of the cell, creating a fan of triangles.
+    /// The cell must be star-shaped from the new vertex (every vertex of the cell is visible from it), which is always the case for a convex cell.
+    ///
+    /// The first triangle keeps the index of the cell, the other ones are new parents.
+    pub fn insert_vertex_in_cell(
+        &mut self,
+        parent: ParentIndex,
+        pos: Point2<f64>,
+    ) -> Result<VertexIndex, MeshError> {
+        if parent >= ParentIndex(self.0.parents_len()) {
+            return Err(MeshError::ParentIndexOutOfBound {
+                got: parent,
+                len: self.0.parents_len(),
+            });
+        }
+
+        if self.0.parents[parent] != Parent::Cell {
+            return Err(MeshError::ParentIsNotACell { parent });
+        }
+
+        let cell_he = self.0.he_from_parent(parent);
+        for he in &cell_he {
+            let [start, end] = self.0.vertices_from_he(*he);
+            let edge = self.0.vertices[end] - self.0.vertices[start];
+            if edge.perp(&(pos - self.0.vertices[start])) <= 0.0 {
+                return Err(MeshError::NotStarShaped { parent });
+            }
+        }
+
+        let new_vertex = VertexIndex(self.0.vertices_len());
+        self.0.vertices.push(pos);
+        self.0.cache_new_vertex();
+
+        // Spoke i is made of an half-edge going to the new vertex and its twin leaving it
+        let first_spoke = self.0.he_len();
+        let spoke_in = |i: usize| HalfEdgeIndex(first_spoke + 2 * (i % cell_he.len()));
+        let spoke_out = |i: usize| HalfEdgeIndex(first_spoke + 2 * (i % cell_he.len()) + 1);
+
+        for (i, he) in cell_he.iter().enumerate() {
+            self.0.he_to_vertex.push(self.0.he_to_vertex[*he]);
+            self.0.he_to_vertex.push(new_vertex);
+            self.0.cache_new_he(spoke_in(i));
+            self.0.cache_new_he(spoke_out(i));
+            self.0.he_to_twin.push(spoke_out(i));
+            self.0.he_to_twin.push(spoke_in(i));
+            self.0.he_to_next_he.push(HalfEdgeIndex(usize::MAX));
+            self.0.he_to_next_he.push(HalfEdgeIndex(usize::MAX));
+            self.0.he_to_prev_he.push(HalfEdgeIndex(usize::MAX));
+            self.0.he_to_prev_he.push(HalfEdgeIndex(usize::MAX));
+            self.0.he_to_parent.push(parent);
+            self.0.he_to_parent.push(parent);
+        }
+
+        // Triangle i is made of the cell half-edge i, the spoke going in from the next vertex and the spoke going out to vertex i
+        for (i, he) in cell_he.iter().enumerate() {
+            let cell = if i == 0 {
+                parent
+            } else {
+                self.0.parents.push(Parent::Cell);
+                self.0.parent_to_first_he.push(*he);
+                ParentIndex(self.0.parents_len() - 1)
+            };
+
+            let triangle = [*he, spoke_in(i + 1), spoke_out(i)];
+            for j in 0..3 {
+                self.0.he_to_next_he[triangle[j]] = triangle[(j + 1) % 3];
+                self.0.he_to_prev_he[triangle[(j + 1) % 3]] = triangle[j];
+                self.0.he_to_parent[triangle[j]] = cell;
+            }
+        }
+        self.0.parent_to_first_he[parent] = cell_he[0];
+
+        Ok(new_vertex)
+    }
+
+    /// Refines the cells with the largest area by inserting a vertex at their centroid, until the mesh has at least ```target_cells``` cells.
+    /// Each pass goes once through the cells from the largest to the smallest, and the refinement stops after ```max_passes``` passes anyway.
+    /// A cell that is not star-shaped from its centroid (e.g. a non-convex domain) is split along its first valid diagonal instead.
+    ///
+    /// Returns the number of refined cells.
+    pub fn refine_to_target(
+        &mut self,
+        target_cells: usize,
+        max_passes: usize,
+    ) -> Result<usize, MeshError> {
+        let mut cells_len = self.0.cells_len();
+        let mut refined = 0;
+
+        for _ in 0..max_passes {
+            if cells_len >= target_cells {
+                break;
+            }
+
+            let mut cells: Vec<(ParentIndex, f64)> = (0..self.0.parents_len())
+                .map(ParentIndex)
+                .filter(|parent| self.0.parents[*parent] == Parent::Cell)
+                .map(|parent| (parent, self.0.area_from_parent(parent)))
+                .collect();
+            cells.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+            for (cell, _) in cells {
+                if cells_len >= target_cells {
+                    break;
+                }
+                let edges_len = self.0.he_from_parent(cell).len();
+                let centroid = self.0.centroid_from_parent(cell);
+                match self.insert_vertex_in_cell(cell, centroid) {
+                    Ok(_) => cells_len += edges_len - 1,
+                    Err(MeshError::NotStarShaped { .. }) => {
+                        // A simple polygon with more than three vertices always has a diagonal inside of it
+                        let vertices = self.0.vertices_from_parent(cell);
+                        let split = (0..vertices.len())
+                            .flat_map(|i| (i + 2..vertices.len()).map(move |j| (i, j)))
+                            .any(|(i, j)| {
+                                self.try_add_edge_between_vertices((vertices[i], vertices[j]), cell)
+                                    .is_ok()
+                            });
+                        if !split {
+                            continue;
+                        }
+                        cells_len += 1;
+                    }
+                    Err(error) => return Err(error),
+                }
+                refined += 1;
+            }
+        }
+
+        Ok(refined)
+    }
+
+    /// Flips the edge shared by two triangle cells if it does not respect the Delaunay criterion,
+    /// i.e. if the opposite vertex of one triangle is inside the circumcircle of the other one.
+    /// Almost cocircular vertices are left untouched.
+    ///
+    /// Returns whether the edge was flipped.
+    pub fn delaunay_flip(&mut self, he_id: HalfEdgeIndex) -> Result<bool, MeshError> {
+        if he_id >= HalfEdgeIndex(self.0.he_len()) {
+            return Err(MeshError::HalfEdgeIndexOutOfBound {
+                got: he_id,
+                len: self.0.he_len(),
+            });
+        }
+
+        let twin = self.0.twin_from_he(he_id);
+        for he in [he_id, twin] {
+            let parent = self.0.parent_from_he(he);
+            if self.0.parents[parent] != Parent::Cell {
+                return Err(MeshError::ParentIsNotACell { parent });
+            }
+            let edges_len = self.0.he_iter_from_parent(parent).count();
+            if edges_len != 3 {
+                return Err(MeshError::WrongEdgeCount {
+                    parent,
+                    expected: 3,
+                    got: edges_len,
+                });
+            }
+        }
+
+        // he_id goes from a to b in the triangle (a, b, c), its twin in the triangle (b, a, d)
+        let (he_next, he_prev) = (self.0.he_to_next_he[he_id], self.0.he_to_prev_he[he_id]);
+        let (twin_next, twin_prev) = (self.0.he_to_next_he[twin], self.0.he_to_prev_he[twin]);
+        let [a, b] = self.0.vertices_from_he(he_id);
+        let c = self.0.he_to_vertex[he_prev];
+        let d = self.0.he_to_vertex[twin_prev];
+        let [pa, pb, pc, pd] = [a, b, c, d].map(|vertex| self.0.vertices[vertex]);
+
+        let epsilon = 1e-10 * (pb - pa).norm_squared().powi(2);
+        if !geometry::in_circle(pa, pb, pc, pd, epsilon) {
+            return Ok(false);
+        }
+
+        // The new edge must be inside the quad (a, d, b, c)
+        if (pd - pc).perp(&(pa - pc)) * (pd - pc).perp(&(pb - pc)) >= 0.0 {
+            return Ok(false);
+        }
+
+        // he_id now goes from d to c in the triangle (d, c, a), its twin from c to d in the triangle (c, d, b)
+        let (parent, twin_parent) = (self.0.he_to_parent[he_id], self.0.he_to_parent[twin]);
+        self.0.set_he_origin(he_id, d);
+        self.0.set_he_origin(twin, c);
+
+        for (triangle, triangle_parent) in [
+            ([he_id, he_prev, twin_next], parent),
+            ([twin, twin_prev, he_next], twin_parent),
+        ] {
+            for i in 0..3 {
+                self.0.he_to_next_he[triangle[i]] = triangle[(i + 1) % 3];
+                self.0.he_to_prev_he[triangle[(i + 1) % 3]] = triangle[i];
+                self.0.he_to_parent[triangle[i]] = triangle_parent;
+            }
+            self.0.parent_to_first_he[triangle_parent] = triangle[0];
+        }
+
+        Ok(true)
+    }
+
+    /// Removes a vertex that is not connected to any edge anymore, typically after an edge collapse.
+    /// The last vertex is moved in its place, so its index changes.
+    pub fn remove_vertex(&mut self, vertex_id: VertexIndex) -> Result<(), MeshError> {
+        if vertex_id >= VertexIndex(self.0.vertices_len()) {
+            return Err(MeshError::VertexIndexOutOfBound {
+                got: vertex_id,
+                len: self.0.vertices_len(),
+            });
+        }
+        if !self.0.he_from_vertex(vertex_id).is_empty() {
+            return Err(MeshError::VertexStillConnected { vertex: vertex_id });
+        }
+
+        self.0.swap_remove_vertex(vertex_id);
+
+        Ok(())
+    }
+}
+
+/// Parses the whitespace separated values of the next line of a Gmsh file.
+fn next_gmsh_values<T: FromStr>(lines: &[&str], current: &mut usize) -> Result<Vec<T>, MeshError> {
+    *current += 1;
+    let line = lines
+        .get(*current - 1)
+        .ok_or(MeshError::ParsingError { line: *current })?;
+    line.split_whitespace()
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| MeshError::ParsingError { line: *current })
+        })
+        .collect()
+}
+
+/// Parses the number of items of a Gmsh section.
+fn next_gmsh_count(lines: &[&str], current: &mut usize) -> Result<usize, MeshError> {
+    match next_gmsh_values(lines, current)?.as_slice() {
+        [count] => Ok(*count),
+        _ => Err(MeshError::ParsingError { line: *current }),
+    }
 }
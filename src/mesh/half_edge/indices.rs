@@ -115,3 +115,17 @@ impl fmt::Display for VertexIndex {
         write!(f, "{}", self.0)
     }
 }
+
+impl Index<VertexIndex> for Vec<Vec<HalfEdgeIndex>> {
+    type Output = Vec<HalfEdgeIndex>;
+
+    fn index(&self, index: VertexIndex) -> &Self::Output {
+        &self[index.0]
+    }
+}
+
+impl IndexMut<VertexIndex> for Vec<Vec<HalfEdgeIndex>> {
+    fn index_mut(&mut self, index: VertexIndex) -> &mut Self::Output {
+        &mut self[index.0]
+    }
+}
@@ -19,7 +19,12 @@ fn simple_mesh() -> Modifiable2DMesh {
     let mesh;
 
     unsafe {
-        mesh = Modifiable2DMesh::new_from_boundary(vertices, edge_to_vertices_and_parent, parents);
+        mesh = Modifiable2DMesh::new_from_boundary(
+            vertices,
+            edge_to_vertices_and_parent,
+            parents,
+            Winding::Ccw,
+        );
     }
 
     mesh
@@ -94,3 +99,1054 @@ fn combined_test() {
     
     mesh.0.check_mesh().unwrap();
 }
+
+fn signed_parent_area(mesh: &Base2DMesh, parent: ParentIndex) -> f64 {
+    let points: Vec<Point2<f64>> = mesh
+        .vertices_from_parent(parent)
+        .into_iter()
+        .map(|vertex| mesh.vertices[vertex])
+        .collect();
+    geometry::signed_polygon_area(&points)
+}
+
+#[test]
+fn split_quad_test_1() {
+    for diagonal in [false, true] {
+        let mut mesh = simple_mesh();
+        let quad_area = mesh.0.area_from_parent(ParentIndex(1));
+
+        let new_parent = mesh.split_quad(ParentIndex(1), diagonal).unwrap();
+
+        mesh.0.check_mesh().unwrap();
+        assert_eq!(mesh.0.vertices_from_parent(ParentIndex(1)).len(), 3);
+        assert_eq!(mesh.0.vertices_from_parent(new_parent).len(), 3);
+        let area = mesh.0.area_from_parent(ParentIndex(1)) + mesh.0.area_from_parent(new_parent);
+        assert!((area - quad_area).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn split_quad_test_2() {
+    let mut mesh = simple_mesh();
+
+    assert_eq!(
+        mesh.split_quad(ParentIndex(0), false),
+        Err(MeshError::ParentIsNotACell {
+            parent: ParentIndex(0)
+        })
+    );
+
+    let new_parent = mesh.split_quad(ParentIndex(1), false).unwrap();
+    assert_eq!(
+        mesh.split_quad(new_parent, false),
+        Err(MeshError::WrongEdgeCount {
+            parent: new_parent,
+            expected: 4,
+            got: 3
+        })
+    );
+}
+
+fn circle_mesh(n: usize, radius: f64) -> Modifiable2DMesh {
+    polygon_mesh(
+        (0..n)
+            .map(|i| {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+                Point2::new(radius * angle.cos(), radius * angle.sin())
+            })
+            .collect(),
+    )
+}
+
+/// Single cell mesh from a counter-clockwise polygon
+fn polygon_mesh(vertices: Vec<Point2<f64>>) -> Modifiable2DMesh {
+    let n = vertices.len();
+    let parents = vec![Parent::Boundary(Boundary::NoSlip)];
+    let edge_to_vertices_and_parent = (0..n)
+        .map(|i| (VertexIndex(i), VertexIndex((i + 1) % n), ParentIndex(0)))
+        .collect();
+
+    unsafe {
+        Modifiable2DMesh::new_from_boundary(
+            vertices,
+            edge_to_vertices_and_parent,
+            parents,
+            Winding::Ccw,
+        )
+    }
+}
+
+#[test]
+fn boundary_curvature_test_1() {
+    let mesh = circle_mesh(64, 2.0);
+
+    for i in 0..mesh.0.vertices_len() {
+        let curvature = mesh.0.boundary_curvature(VertexIndex(i)).unwrap();
+        assert!((curvature - 0.5).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn boundary_curvature_test_2() {
+    let mut mesh = simple_mesh();
+
+    unsafe {
+        mesh.add_edge_between_vertices((VertexIndex(0), VertexIndex(2)), ParentIndex(1))
+            .unwrap();
+    }
+    mesh.split_edge(HalfEdgeIndex(8), 0.5).unwrap();
+
+    let corner = mesh.0.boundary_curvature(VertexIndex(1)).unwrap();
+    assert!((corner - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    assert_eq!(mesh.0.boundary_curvature(VertexIndex(4)), None);
+}
+
+#[test]
+fn new_from_boundary_test_2() {
+    let vertices = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+    let ccw = vec![
+        (VertexIndex(0), VertexIndex(1), ParentIndex(0)),
+        (VertexIndex(1), VertexIndex(2), ParentIndex(0)),
+        (VertexIndex(2), VertexIndex(3), ParentIndex(0)),
+        (VertexIndex(3), VertexIndex(0), ParentIndex(0)),
+    ];
+    let cw = vec![
+        (VertexIndex(0), VertexIndex(3), ParentIndex(0)),
+        (VertexIndex(3), VertexIndex(2), ParentIndex(0)),
+        (VertexIndex(2), VertexIndex(1), ParentIndex(0)),
+        (VertexIndex(1), VertexIndex(0), ParentIndex(0)),
+    ];
+
+    for (edges, winding) in [(ccw, Winding::Ccw), (cw, Winding::Cw)] {
+        let parents = vec![Parent::Boundary(Boundary::NoSlip)];
+        let mesh = unsafe {
+            Modifiable2DMesh::new_from_boundary(vertices.clone(), edges, parents, winding)
+        };
+
+        mesh.0.check_mesh().unwrap();
+        assert_eq!(mesh.0.parents_len(), 2);
+        assert_eq!(mesh.0.parent_from_index(ParentIndex(1)), &Parent::Cell);
+        // Counter-clockwise cell, so the right hand normals point outward
+        assert!((signed_parent_area(&mesh.0, ParentIndex(1)) - 1.0).abs() < 1e-12);
+        assert!((signed_parent_area(&mesh.0, ParentIndex(0)) + 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn boundary_half_edges_test_1() {
+    let mesh = simple_mesh();
+
+    assert_eq!(
+        mesh.0.boundary_half_edges(),
+        vec![
+            HalfEdgeIndex(1),
+            HalfEdgeIndex(3),
+            HalfEdgeIndex(5),
+            HalfEdgeIndex(7)
+        ]
+    );
+}
+
+#[test]
+fn memory_usage_test_1() {
+    let mut mesh = simple_mesh();
+    let initial = mesh.0.memory_usage();
+
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+    unsafe {
+        mesh.add_edge_between_vertices((VertexIndex(4), VertexIndex(2)), ParentIndex(1))
+            .unwrap();
+    }
+
+    assert!(mesh.0.memory_usage() > initial);
+}
+
+#[test]
+fn simplify_boundary_test_1() {
+    let mut mesh = simple_mesh();
+
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+    mesh.split_edge(HalfEdgeIndex(4), 0.3).unwrap();
+    mesh.0.check_mesh().unwrap();
+
+    assert_eq!(mesh.simplify_boundary(1e-6).unwrap(), 3);
+
+    mesh.0.check_mesh().unwrap();
+    assert_eq!(mesh.0.vertices_len(), 4);
+    assert_eq!(mesh.0.he_len(), 8);
+    assert_eq!(mesh.0.boundary_half_edges().len(), 4);
+    assert!((mesh.0.area_from_parent(ParentIndex(1)) - 1.0).abs() < 1e-12);
+
+    // Corners are kept
+    assert_eq!(mesh.simplify_boundary(1.0).unwrap(), 0);
+}
+
+#[test]
+fn simplify_boundary_test_2() {
+    let mut mesh = simple_mesh();
+
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+    unsafe {
+        mesh.add_edge_between_vertices((VertexIndex(4), VertexIndex(2)), ParentIndex(1))
+            .unwrap();
+    }
+
+    // The vertex is connected to an interior edge
+    assert_eq!(mesh.simplify_boundary(1e-6).unwrap(), 0);
+    assert_eq!(
+        mesh.simplify_boundary(-1.0),
+        Err(MeshError::WrongFloatValue {
+            got: -1.0,
+            expected: (0.0, std::f64::consts::PI)
+        })
+    );
+}
+
+#[test]
+fn simplify_boundary_test_3() {
+    let mut mesh = simple_mesh();
+
+    // Each side is split in three, with the vertex adjacency cache kept up to date
+    mesh.0.rebuild_vertex_adjacency();
+    for he in mesh.0.he_from_parent(ParentIndex(1)) {
+        mesh.split_edge(he, 0.6).unwrap();
+        mesh.split_edge(he, 0.5).unwrap();
+    }
+    mesh.0.check_mesh().unwrap();
+    assert_eq!(mesh.0.vertices_len(), 12);
+
+    assert_eq!(mesh.simplify_boundary(1e-6).unwrap(), 8);
+
+    mesh.0.check_mesh().unwrap();
+    assert_eq!(mesh.0.vertices_len(), 4);
+    assert_eq!(mesh.0.he_len(), 8);
+    assert_eq!(mesh.0.boundary_half_edges().len(), 4);
+    assert!((mesh.0.area_from_parent(ParentIndex(1)) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn corner_normals_test_1() {
+    let mut mesh = simple_mesh();
+
+    for i in 0..4 {
+        let normals = mesh.0.corner_normals(VertexIndex(i));
+        assert_eq!(normals.len(), 2);
+        assert!(normals[0].dot(&normals[1]).abs() < 1e-12);
+        // Outward normals point away from the center of the square
+        let outward = mesh.0.vertices[VertexIndex(i)] - Point2::new(0.5, 0.5);
+        assert!(normals.iter().all(|normal| normal.dot(&outward) > 0.0));
+    }
+
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+    assert_eq!(
+        mesh.0.corner_normals(VertexIndex(4)),
+        vec![Vector2::new(0.0, -1.0)]
+    );
+}
+
+#[test]
+fn min_angle_from_parent_test_1() {
+    // Concave pentagon, the reflex angle at (2, 0.5) is not the smallest one
+    let vertices = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(2.2, 4.0),
+        Point2::new(2.0, 0.5),
+        Point2::new(1.8, 4.0),
+    ];
+    let mesh = mesh_from_cells(vertices, &[vec![0, 1, 2, 3, 4]]);
+
+    let expected = 0.45f64.atan() + (0.2f64 / 3.5).atan();
+    assert!((mesh.min_angle_from_parent(ParentIndex(0)) - expected).abs() < 1e-12);
+}
+
+#[test]
+fn enforce_min_angle_test_1() {
+    let mut mesh = simple_mesh();
+    mesh.split_quad(ParentIndex(1), false).unwrap();
+
+    assert_eq!(mesh.enforce_min_angle(30f64.to_radians(), 10).unwrap(), 0);
+}
+
+#[test]
+fn enforce_min_angle_test_2() {
+    // Rectangle split along its diagonal, the smallest angles are atan(1/4)
+    let vertices = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(4.0, 0.0),
+        Point2::new(4.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+    let mut mesh = Modifiable2DMesh(mesh_from_cells(vertices, &[vec![0, 1, 2], vec![0, 2, 3]]));
+    assert!((mesh.0.min_angle_from_parent(ParentIndex(0)) - 0.25f64.atan()).abs() < 1e-12);
+
+    let min_angle = 20f64.to_radians();
+    let insertions = mesh.enforce_min_angle(min_angle, 100).unwrap();
+    assert!(insertions > 0);
+
+    mesh.0.check_mesh().unwrap();
+    let cells: Vec<ParentIndex> = (0..mesh.0.parents_len())
+        .map(ParentIndex)
+        .filter(|parent| mesh.0.parents[*parent] == Parent::Cell)
+        .collect();
+    for cell in &cells {
+        assert_eq!(mesh.0.he_from_parent(*cell).len(), 3);
+        assert!(mesh.0.min_angle_from_parent(*cell) >= min_angle);
+    }
+    let area: f64 = cells
+        .iter()
+        .map(|cell| mesh.0.area_from_parent(*cell))
+        .sum();
+    assert!((area - 4.0).abs() < 1e-12);
+}
+
+/// Square split in four triangles around a central vertex (index 4)
+fn fan_mesh() -> Modifiable2DMesh {
+    let mut mesh = simple_mesh();
+
+    unsafe {
+        mesh.add_edge_between_vertices((VertexIndex(0), VertexIndex(2)), ParentIndex(1))
+            .unwrap();
+    }
+    mesh.split_edge(HalfEdgeIndex(8), 0.5).unwrap();
+    for parent in [ParentIndex(1), ParentIndex(2)] {
+        let vertices = mesh.0.vertices_from_parent(parent);
+        let opposite = if vertices.contains(&VertexIndex(1)) {
+            VertexIndex(1)
+        } else {
+            VertexIndex(3)
+        };
+        unsafe {
+            mesh.add_edge_between_vertices((VertexIndex(4), opposite), parent)
+                .unwrap();
+        }
+    }
+
+    mesh
+}
+
+#[test]
+fn vertex_laplacian_test_1() {
+    let mesh = fan_mesh();
+    mesh.0.check_mesh().unwrap();
+
+    let field: Vec<f64> = (0..mesh.0.vertices_len())
+        .map(|i| {
+            let vertex = mesh.0.vertices[VertexIndex(i)];
+            2.0 * vertex.x + 3.0 * vertex.y + 1.0
+        })
+        .collect();
+    let laplacian = mesh.0.vertex_laplacian(&field);
+
+    assert_eq!(laplacian.len(), 5);
+    assert!(laplacian[4].abs() < 1e-12);
+    // The corner (0, 0) only sees (1, 0) and (0, 1)
+    assert!((laplacian[0] - 2.5).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "The field must have one value per vertex")]
+fn vertex_laplacian_test_2() {
+    let mesh = fan_mesh();
+    mesh.0.vertex_laplacian(&[0.0; 4]);
+}
+
+#[test]
+fn insert_vertex_in_cell_test_1() {
+    let mut mesh = simple_mesh();
+
+    let vertex = mesh
+        .insert_vertex_in_cell(ParentIndex(1), Point2::new(0.3, 0.6))
+        .unwrap();
+
+    mesh.0.check_mesh().unwrap();
+    assert_eq!(vertex, VertexIndex(4));
+    assert_eq!(mesh.0.cells_len(), 4);
+    assert_eq!(mesh.0.he_from_vertex(vertex).len(), 4);
+    let area: f64 = (1..5)
+        .map(|i| mesh.0.area_from_parent(ParentIndex(i)))
+        .sum();
+    assert!((area - 1.0).abs() < 1e-12);
+
+    assert_eq!(
+        mesh.insert_vertex_in_cell(ParentIndex(1), Point2::new(2.0, 0.5)),
+        Err(MeshError::NotStarShaped {
+            parent: ParentIndex(1)
+        })
+    );
+}
+
+#[test]
+fn refine_to_target_test_1() {
+    let mut mesh = polygon_mesh(vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+    ]);
+
+    mesh.refine_to_target(50, 10).unwrap();
+
+    mesh.0.check_mesh().unwrap();
+    assert!(mesh.0.cells_len() >= 50);
+    let area: f64 = (0..mesh.0.parents_len())
+        .map(ParentIndex)
+        .filter(|parent| mesh.0.parent_from_index(*parent) == &Parent::Cell)
+        .map(|parent| mesh.0.area_from_parent(parent))
+        .sum();
+    assert!((area - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn refine_to_target_test_2() {
+    // C-shaped cell, its centroid is in the notch
+    let mut mesh = polygon_mesh(vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(3.0, 1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 2.0),
+        Point2::new(3.0, 2.0),
+        Point2::new(3.0, 3.0),
+        Point2::new(0.0, 3.0),
+    ]);
+
+    assert!(mesh.refine_to_target(50, 10).unwrap() > 0);
+
+    mesh.0.check_mesh().unwrap();
+    assert!(mesh.0.cells_len() >= 50);
+    let area: f64 = (0..mesh.0.parents_len())
+        .map(ParentIndex)
+        .filter(|parent| mesh.0.parent_from_index(*parent) == &Parent::Cell)
+        .map(|parent| {
+            let area = mesh.0.area_from_parent(parent);
+            assert!(area > 0.0);
+            area
+        })
+        .sum();
+    assert!((area - 7.0).abs() < 1e-12);
+}
+
+#[test]
+fn try_add_edge_between_vertices_test_1() {
+    let mut mesh = simple_mesh();
+
+    // Concave cell with vertex 4 pushed inside
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+    *mesh.vertex_mut_from_index(VertexIndex(4)) = Point2::new(0.5, 0.8);
+
+    assert_eq!(
+        mesh.try_add_edge_between_vertices((VertexIndex(2), VertexIndex(3)), ParentIndex(1)),
+        Err(MeshError::AlreadyExists)
+    );
+    assert_eq!(
+        mesh.try_add_edge_between_vertices((VertexIndex(0), VertexIndex(2)), ParentIndex(1)),
+        Err(MeshError::EdgeWouldCross {
+            he: HalfEdgeIndex(8)
+        })
+    );
+
+    // Below the notch, outside of the cell
+    assert_eq!(
+        mesh.try_add_edge_between_vertices((VertexIndex(0), VertexIndex(1)), ParentIndex(1)),
+        Err(MeshError::EdgeOutsideParent {
+            parent: ParentIndex(1)
+        })
+    );
+
+    mesh.try_add_edge_between_vertices((VertexIndex(4), VertexIndex(2)), ParentIndex(1))
+        .unwrap();
+    mesh.0.check_mesh().unwrap();
+}
+
+#[test]
+fn try_add_edge_between_vertices_test_2() {
+    let mut mesh = simple_mesh();
+
+    // Vertex 4 in the middle of the edge from 0 to 1
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+
+    let overlapped = mesh
+        .0
+        .he_from_parent(ParentIndex(1))
+        .into_iter()
+        .find(|he| mesh.0.vertices_from_he(*he) == [VertexIndex(0), VertexIndex(4)])
+        .unwrap();
+    assert_eq!(
+        mesh.try_add_edge_between_vertices((VertexIndex(0), VertexIndex(1)), ParentIndex(1)),
+        Err(MeshError::EdgeWouldCross { he: overlapped })
+    );
+    assert!(matches!(
+        mesh.try_add_edge_between_vertices((VertexIndex(1), VertexIndex(0)), ParentIndex(1)),
+        Err(MeshError::EdgeWouldCross { .. })
+    ));
+
+    mesh.try_add_edge_between_vertices((VertexIndex(4), VertexIndex(2)), ParentIndex(1))
+        .unwrap();
+    mesh.0.check_mesh().unwrap();
+}
+
+#[test]
+fn try_add_edge_between_vertices_test_3() {
+    let mut mesh = simple_mesh();
+    let unchanged = mesh.clone();
+
+    // The boundary loop runs clockwise around the domain
+    assert_eq!(
+        mesh.try_add_edge_between_vertices((VertexIndex(0), VertexIndex(2)), ParentIndex(0)),
+        Err(MeshError::ParentIsNotACell {
+            parent: ParentIndex(0)
+        })
+    );
+    assert_eq!(
+        mesh.try_add_edge_between_vertices((VertexIndex(0), VertexIndex(0)), ParentIndex(1)),
+        Err(MeshError::SelfLoopEdge {
+            vertex: VertexIndex(0)
+        })
+    );
+    assert_eq!(mesh, unchanged);
+
+    // Two triangles, only one of them containing vertex 1
+    mesh.try_add_edge_between_vertices((VertexIndex(0), VertexIndex(2)), ParentIndex(1))
+        .unwrap();
+    let unchanged = mesh.clone();
+    let parent = [ParentIndex(1), ParentIndex(2)]
+        .into_iter()
+        .find(|parent| {
+            !mesh
+                .0
+                .vertices_from_parent(*parent)
+                .contains(&VertexIndex(1))
+        })
+        .unwrap();
+    assert_eq!(
+        mesh.try_add_edge_between_vertices((VertexIndex(1), VertexIndex(3)), parent),
+        Err(MeshError::ParentDoesNotContainVertex {
+            vertex: VertexIndex(1),
+            parent
+        })
+    );
+    assert_eq!(mesh, unchanged);
+    mesh.0.check_mesh().unwrap();
+}
+
+#[test]
+fn vertex_adjacency_test_1() {
+    let mut mesh = simple_mesh();
+    mesh.0.rebuild_vertex_adjacency();
+    mesh.0.check_mesh().unwrap();
+
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+    mesh.split_edge(HalfEdgeIndex(2), 0.5).unwrap();
+    mesh.0.check_mesh().unwrap();
+    unsafe {
+        mesh.add_edge_between_vertices((VertexIndex(4), VertexIndex(3)), ParentIndex(1))
+            .unwrap();
+        mesh.extract_vertex_from_edge(HalfEdgeIndex(4), Point2::new(1.2, 0.5))
+            .unwrap();
+    }
+    mesh.0.check_mesh().unwrap();
+    mesh.insert_vertex_in_cell(ParentIndex(1), mesh.0.centroid_from_parent(ParentIndex(1)))
+        .unwrap();
+    mesh.0.check_mesh().unwrap();
+    mesh.simplify_boundary(1e-6).unwrap();
+    mesh.0.check_mesh().unwrap();
+
+    let mut uncached = mesh.clone();
+    uncached.0.vertex_to_he = None;
+    assert_eq!(mesh, uncached);
+    for i in 0..mesh.0.vertices_len() {
+        let mut cached = mesh.0.he_from_vertex(VertexIndex(i));
+        assert_eq!(
+            mesh.0.he_slice_from_vertex(VertexIndex(i)),
+            Some(cached.as_slice())
+        );
+        cached.sort_by_key(|he| he.0);
+        assert_eq!(cached, uncached.0.he_from_vertex(VertexIndex(i)));
+        assert_eq!(uncached.0.he_slice_from_vertex(VertexIndex(i)), None);
+    }
+
+    // Existing edges are found from the half-edges of the first vertex, in both directions
+    let [start, end] = mesh.0.vertices_from_he(HalfEdgeIndex(0));
+    let parent = mesh.0.he_to_parent[HalfEdgeIndex(0)];
+    for vertices in [(start, end), (end, start)] {
+        unsafe {
+            assert_eq!(
+                mesh.add_edge_between_vertices(vertices, parent),
+                Err(MeshError::AlreadyExists)
+            );
+            assert_eq!(
+                uncached.add_edge_between_vertices(vertices, parent),
+                Err(MeshError::AlreadyExists)
+            );
+        }
+    }
+}
+
+#[test]
+fn vertex_adjacency_test_2() {
+    let mut mesh = simple_mesh();
+    mesh.0.rebuild_vertex_adjacency();
+
+    mesh.0.vertex_to_he.as_mut().unwrap()[0].pop();
+    assert_eq!(mesh, simple_mesh());
+    assert_eq!(
+        mesh.0.check_mesh(),
+        Err(MeshError::VertexAdjacencyNotCorrect {
+            vertex: VertexIndex(0)
+        })
+    );
+}
+
+/// Builds a mesh from counter-clockwise cells, each boundary loop gets its own boundary parent
+fn mesh_from_cells(vertices: Vec<Point2<f64>>, cells: &[Vec<usize>]) -> Base2DMesh {
+    let cells: Vec<Vec<VertexIndex>> = cells
+        .iter()
+        .map(|cell| cell.iter().map(|vertex| VertexIndex(*vertex)).collect())
+        .collect();
+    Modifiable2DMesh::new_from_cells(vertices, &cells, Boundary::NoSlip)
+        .unwrap()
+        .0
+}
+
+/// Square with a square hole, made of four trapezoids
+fn annulus_mesh() -> Base2DMesh {
+    let vertices = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(3.0, 3.0),
+        Point2::new(0.0, 3.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(2.0, 1.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(1.0, 2.0),
+    ];
+    let cells = [
+        vec![0, 1, 5, 4],
+        vec![1, 2, 6, 5],
+        vec![2, 3, 7, 6],
+        vec![3, 0, 4, 7],
+    ];
+
+    mesh_from_cells(vertices, &cells)
+}
+
+#[test]
+fn num_boundary_loops_test_1() {
+    let mesh = simple_mesh();
+    assert_eq!(mesh.0.num_boundary_loops(), 1);
+
+    let mesh = annulus_mesh();
+    mesh.check_mesh().unwrap();
+    assert_eq!(mesh.num_boundary_loops(), 2);
+}
+
+#[test]
+fn export_vtu_test_1() {
+    let mesh = fan_mesh();
+
+    mesh.0.export_vtu("./output/test_fan.vtu").unwrap();
+
+    let content = std::fs::read_to_string("./output/test_fan.vtu").unwrap();
+    // The boundary parent is not exported
+    assert!(content.contains("NumberOfPoints=\"5\" NumberOfCells=\"4\""));
+}
+
+#[test]
+fn he_iter_from_parent_test_1() {
+    let mesh = fan_mesh();
+
+    for i in 0..mesh.0.parents_len() {
+        let parent = ParentIndex(i);
+        let mut iter = mesh.0.he_iter_from_parent(parent);
+        let mut expected = vec![mesh.0.parent_to_first_he[parent]];
+        while mesh.0.next_he_from_he(*expected.last().unwrap()) != expected[0] {
+            expected.push(mesh.0.next_he_from_he(*expected.last().unwrap()));
+        }
+
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), expected);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(mesh.0.he_from_parent(parent), expected);
+    }
+}
+
+#[test]
+fn delaunay_flip_test_1() {
+    let mut mesh = polygon_mesh(vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, -0.3),
+        Point2::new(2.0, 0.0),
+        Point2::new(1.0, 0.3),
+    ]);
+    mesh.0.rebuild_vertex_adjacency();
+    unsafe {
+        mesh.add_edge_between_vertices((VertexIndex(0), VertexIndex(2)), ParentIndex(1))
+            .unwrap();
+    }
+
+    assert!(mesh.delaunay_flip(HalfEdgeIndex(8)).unwrap());
+    mesh.0.check_mesh().unwrap();
+    let mut diagonal = mesh.0.vertices_from_he(HalfEdgeIndex(8));
+    diagonal.sort_by_key(|vertex| vertex.0);
+    assert_eq!(diagonal, [VertexIndex(1), VertexIndex(3)]);
+    let area = mesh.0.area_from_parent(ParentIndex(1)) + mesh.0.area_from_parent(ParentIndex(2));
+    assert!((area - 0.6).abs() < 1e-12);
+
+    // Already Delaunay
+    assert!(!mesh.delaunay_flip(HalfEdgeIndex(9)).unwrap());
+}
+
+#[test]
+fn delaunay_flip_test_2() {
+    let mut mesh = simple_mesh();
+    mesh.split_quad(ParentIndex(1), false).unwrap();
+
+    // The four vertices of the square are cocircular
+    assert!(!mesh.delaunay_flip(HalfEdgeIndex(8)).unwrap());
+    assert_eq!(
+        mesh.delaunay_flip(HalfEdgeIndex(0)),
+        Err(MeshError::ParentIsNotACell {
+            parent: ParentIndex(0)
+        })
+    );
+}
+
+#[test]
+fn new_from_cells_test_1() {
+    let vertices = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(1.0, 1.0),
+    ];
+
+    // Second cell given clockwise
+    let cells = vec![
+        vec![VertexIndex(0), VertexIndex(1), VertexIndex(3)],
+        vec![VertexIndex(1), VertexIndex(3), VertexIndex(2)],
+    ];
+    let mesh = Modifiable2DMesh::new_from_cells(vertices.clone(), &cells, Boundary::Slip).unwrap();
+    mesh.0.check_mesh().unwrap();
+    assert_eq!(mesh.0.cells_len(), 2);
+    assert_eq!(mesh.0.num_boundary_loops(), 1);
+    assert!(signed_parent_area(&mesh.0, ParentIndex(1)) > 0.0);
+
+    // Same edge three times
+    let cells = vec![
+        vec![VertexIndex(0), VertexIndex(1), VertexIndex(3)],
+        vec![VertexIndex(1), VertexIndex(2), VertexIndex(3)],
+        vec![VertexIndex(0), VertexIndex(1), VertexIndex(3)],
+    ];
+    assert_eq!(
+        Modifiable2DMesh::new_from_cells(vertices, &cells, Boundary::Slip),
+        Err(MeshError::NotManifold {
+            vertex: VertexIndex(0)
+        })
+    );
+}
+
+#[test]
+fn new_from_cells_test_2() {
+    let vertices = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+    ];
+
+    for cell in [
+        vec![],
+        vec![VertexIndex(0), VertexIndex(1)],
+        vec![VertexIndex(0), VertexIndex(1), VertexIndex(0)],
+        vec![
+            VertexIndex(0),
+            VertexIndex(1),
+            VertexIndex(2),
+            VertexIndex(1),
+        ],
+    ] {
+        let cells = vec![vec![VertexIndex(0), VertexIndex(1), VertexIndex(2)], cell];
+        assert_eq!(
+            Modifiable2DMesh::new_from_cells(vertices.clone(), &cells, Boundary::Slip),
+            Err(MeshError::DegenerateCell {
+                parent: ParentIndex(1)
+            })
+        );
+    }
+
+    std::fs::write("./output/test_degenerate.obj", "v 0 0 0\nv 1 0 0\nf 1 2\n").unwrap();
+    assert_eq!(
+        Modifiable2DMesh::from_obj("./output/test_degenerate.obj"),
+        Err(MeshError::DegenerateCell {
+            parent: ParentIndex(0)
+        })
+    );
+}
+
+#[test]
+fn from_gmsh_test_1() {
+    // Unit square split in two triangles, the second one being clockwise
+    let content = "$MeshFormat
+2.2 0 8
+$EndMeshFormat
+$Nodes
+4
+1 0 0 0
+2 1 0 0
+3 1 1 0
+4 0 1 0
+$EndNodes
+$Elements
+7
+1 15 2 0 1 1
+2 1 2 1 1 1 2
+3 1 2 1 2 2 3
+4 1 2 1 3 3 4
+5 1 2 1 4 4 1
+6 2 2 0 1 1 2 3
+7 2 2 0 1 1 4 3
+$EndElements
+";
+    std::fs::write("./output/test_gmsh.msh", content).unwrap();
+
+    let (mesh, tags) = Modifiable2DMesh::from_gmsh("./output/test_gmsh.msh").unwrap();
+    mesh.0.check_mesh().unwrap();
+    assert_eq!(mesh.0.vertices_len(), 4);
+    assert_eq!(mesh.0.cells_len(), 2);
+    assert_eq!(mesh.0.num_boundary_loops(), 1);
+
+    // All the sides are in the same physical group
+    let mut sides: Vec<(usize, usize, usize)> = tags
+        .iter()
+        .map(|(he, physical)| {
+            assert!(matches!(
+                mesh.0.parents[mesh.0.he_to_parent[*he]],
+                Parent::Boundary(_)
+            ));
+            let [start, end] = mesh.0.vertices_from_he(*he);
+            (start.0.min(end.0), start.0.max(end.0), *physical)
+        })
+        .collect();
+    sides.sort();
+    assert_eq!(sides, vec![(0, 1, 1), (0, 3, 1), (1, 2, 1), (2, 3, 1)]);
+
+    // The diagonal is shared by both cells
+    let interior = (0..mesh.0.he_len())
+        .map(HalfEdgeIndex)
+        .filter(|he| {
+            mesh.0.parents[mesh.0.he_to_parent[*he]] == Parent::Cell
+                && mesh.0.parents[mesh.0.he_to_parent[mesh.0.he_to_twin[*he]]] == Parent::Cell
+        })
+        .count();
+    assert_eq!(interior, 2);
+
+    // Lines without physical tag or inside the domain are not returned
+    let untagged = content
+        .replace("2 1 2 1 1 1 2", "2 1 2 0 1 1 2")
+        .replace("3 1 2 1 2 2 3", "3 1 2 5 2 1 3")
+        .replace("4 1 2 1 3 3 4", "4 1 2 7 3 3 4");
+    std::fs::write("./output/test_gmsh.msh", untagged).unwrap();
+    let (mesh, tags) = Modifiable2DMesh::from_gmsh("./output/test_gmsh.msh").unwrap();
+    let mut sides: Vec<(usize, usize, usize)> = tags
+        .iter()
+        .map(|(he, physical)| {
+            let [start, end] = mesh.0.vertices_from_he(*he);
+            (start.0.min(end.0), start.0.max(end.0), *physical)
+        })
+        .collect();
+    sides.sort();
+    assert_eq!(sides, vec![(0, 3, 1), (2, 3, 7)]);
+
+    // Second order triangle
+    let content = content.replace("7 2 2 0 1 1 4 3", "7 9 2 0 1 1 4 3 5 6 7");
+    std::fs::write("./output/test_gmsh.msh", &content).unwrap();
+    assert_eq!(
+        Modifiable2DMesh::from_gmsh("./output/test_gmsh.msh"),
+        Err(MeshError::ParsingError { line: 19 })
+    );
+
+    // Number of tags too large to be added to the offset
+    let content = content.replace(
+        "7 9 2 0 1 1 4 3 5 6 7",
+        &format!("7 2 {} 0 1 1 4 3", usize::MAX),
+    );
+    std::fs::write("./output/test_gmsh.msh", content).unwrap();
+    assert_eq!(
+        Modifiable2DMesh::from_gmsh("./output/test_gmsh.msh"),
+        Err(MeshError::ParsingError { line: 19 })
+    );
+
+    assert_eq!(
+        Modifiable2DMesh::from_gmsh("./output/does_not_exist.msh"),
+        Err(MeshError::FileNotReadable {
+            filename: "./output/does_not_exist.msh".to_string()
+        })
+    );
+}
+
+#[test]
+fn obj_test_1() {
+    let mesh = fan_mesh();
+
+    mesh.0.export_obj("./output/test_fan.obj").unwrap();
+    let imported = Modifiable2DMesh::from_obj("./output/test_fan.obj").unwrap();
+
+    imported.0.check_mesh().unwrap();
+    assert_eq!(imported.0.vertices, mesh.0.vertices);
+    assert_eq!(imported.0.cells_len(), 4);
+    assert_eq!(imported.0.num_boundary_loops(), 1);
+    for parent in (0..imported.0.parents_len()).map(ParentIndex) {
+        if imported.0.parents[parent] == Parent::Cell {
+            assert_eq!(imported.0.vertices_from_parent(parent).len(), 3);
+            assert!(signed_parent_area(&imported.0, parent) > 0.0);
+        }
+    }
+
+    // Texture indices and negative indices
+    std::fs::write(
+        "./output/test_triangle.obj",
+        "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1/1 -2/2 3/3\n",
+    )
+    .unwrap();
+    let imported = Modifiable2DMesh::from_obj("./output/test_triangle.obj").unwrap();
+    imported.0.check_mesh().unwrap();
+    assert_eq!(imported.0.cells_len(), 1);
+
+    std::fs::write("./output/test_triangle.obj", "v 0 0 0\nf 1 2 x\n").unwrap();
+    assert_eq!(
+        Modifiable2DMesh::from_obj("./output/test_triangle.obj"),
+        Err(MeshError::ParsingError { line: 2 })
+    );
+}
+
+#[test]
+fn vertex_valence_test_1() {
+    let mesh = fan_mesh();
+
+    assert_eq!(mesh.0.vertex_valence(VertexIndex(4)), 4);
+    assert!(!mesh.0.is_boundary_vertex(VertexIndex(4)));
+    for i in 0..4 {
+        assert_eq!(mesh.0.vertex_valence(VertexIndex(i)), 3);
+        assert!(mesh.0.is_boundary_vertex(VertexIndex(i)));
+    }
+}
+
+#[test]
+fn boundary_vertices_test_1() {
+    let mesh = annulus_mesh();
+
+    let vertices = mesh.boundary_vertices();
+    assert_eq!(vertices.len(), 8);
+
+    // Consecutive vertices are linked by a boundary half-edge, except between the two loops
+    let boundary_edges: Vec<[VertexIndex; 2]> = mesh
+        .boundary_half_edges()
+        .into_iter()
+        .map(|he| mesh.vertices_from_he(he))
+        .collect();
+    let linked = (0..vertices.len())
+        .filter(|i| boundary_edges.contains(&[vertices[*i], vertices[(i + 1) % vertices.len()]]))
+        .count();
+    assert_eq!(linked, 8 - 2);
+}
+
+#[test]
+fn boundary_loops_test_1() {
+    let mesh = annulus_mesh();
+
+    let loops = mesh.boundary_loops();
+    assert_eq!(loops.len(), 2);
+    for boundary_loop in &loops {
+        assert_eq!(boundary_loop.len(), 4);
+        for i in 0..boundary_loop.len() {
+            assert_eq!(
+                mesh.next_he_from_he(boundary_loop[i]),
+                boundary_loop[(i + 1) % boundary_loop.len()]
+            );
+        }
+    }
+
+    // Outer square and inner square
+    let perimeters: Vec<f64> = loops
+        .iter()
+        .map(|boundary_loop| {
+            boundary_loop
+                .iter()
+                .map(|he| {
+                    let [start, end] = mesh.vertices_from_he(*he);
+                    (mesh.vertices[end] - mesh.vertices[start]).norm()
+                })
+                .sum()
+        })
+        .collect();
+    assert!(perimeters.contains(&12.0));
+    assert!(perimeters.contains(&4.0));
+}
+
+#[test]
+fn euler_characteristic_test_1() {
+    let mut mesh = simple_mesh();
+    assert_eq!(mesh.0.euler_characteristic(), 1);
+    assert_eq!(annulus_mesh().euler_characteristic(), 0);
+
+    // Two separated triangles
+    let vertices = vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(3.0, 0.0),
+        Point2::new(2.0, 1.0),
+    ];
+    let cells = [vec![0, 1, 2], vec![3, 4, 5]];
+    let separated = mesh_from_cells(vertices, &cells);
+    assert_eq!(separated.euler_characteristic(), 2);
+    separated.check_mesh().unwrap();
+
+    // A lost cell
+    mesh.0.parents[ParentIndex(1)] = Parent::None;
+    assert_eq!(
+        mesh.0.check_mesh(),
+        Err(MeshError::UnexpectedEuler {
+            got: 0,
+            expected: 1
+        })
+    );
+}
+
+#[test]
+fn remove_vertex_test_1() {
+    let mut mesh = simple_mesh();
+    mesh.0.rebuild_vertex_adjacency();
+
+    // Isolated vertex 4, then vertex 5 created by the split
+    mesh.0.vertices.push(Point2::new(2.0, 2.0));
+    mesh.0.vertex_to_he.as_mut().unwrap().push(Vec::new());
+    mesh.split_edge(HalfEdgeIndex(0), 0.5).unwrap();
+    let moved = mesh.0.vertices[VertexIndex(5)];
+
+    assert_eq!(
+        mesh.remove_vertex(VertexIndex(0)),
+        Err(MeshError::VertexStillConnected {
+            vertex: VertexIndex(0)
+        })
+    );
+    assert_eq!(
+        mesh.remove_vertex(VertexIndex(6)),
+        Err(MeshError::VertexIndexOutOfBound {
+            got: VertexIndex(6),
+            len: 6
+        })
+    );
+
+    mesh.remove_vertex(VertexIndex(4)).unwrap();
+    mesh.0.check_mesh().unwrap();
+    assert_eq!(mesh.0.vertices_len(), 5);
+    assert_eq!(mesh.0.vertices[VertexIndex(4)], moved);
+    assert_eq!(mesh.0.vertex_valence(VertexIndex(4)), 2);
+}